@@ -1,9 +1,13 @@
-use std::ffi::OsStr;
-use std::process::{Command, Stdio};
+use std::{ffi::OsStr, num::NonZeroUsize, thread};
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+    sync::{mpsc, Mutex},
+};
 
 use anyhow::anyhow;
 
-use crate::package_cache::PackageCacheWriter;
+use crate::{package_cache::PackageCacheWriter, parsing::ParsedArgs};
 use crate::data::{Channel, Package, Target};
 
 
@@ -32,89 +36,157 @@ impl CargoCommand {
         })
     }
 
+    /// Run this command on every combination of the parsed channels and targets and the given
+    /// packages.
+    ///
+    /// If `args.jobs` is greater than one, up to `jobs` cargo processes are run concurrently, each
+    /// with its own target directory (so that they do not wait on each other's build directory
+    /// locks). Their output is buffered, and is then cached and printed in the same order as it
+    /// would be when running sequentially.
+    ///
     /// May panic.
-    pub fn run<S: AsRef<OsStr>>(
-        self,
-        channels:   &[Channel],
-        targets:    &[Target],
-        packages:   &[Package],
-        on_save:    bool,
-        extra_args: &[S],
-    ) {
+    pub fn run(self, args: &ParsedArgs, packages: &[Package]) {
+        let &ParsedArgs { on_save, errors_only, jobs, .. } = args;
+
         // Assume that `--message-format=json` is enabled if and only if
         // `on_save` is true.
         let msg_fmt_json = on_save;
 
-        if self == Self::Test {
-            // `--message-format=json` and targets don't really work for `cargo test`.
-            if msg_fmt_json {
-                return;
+        // `--message-format=json` and targets don't really work for `cargo test`.
+        if self == Self::Test && msg_fmt_json {
+            return;
+        }
+
+        let parallel = jobs.get() > 1;
+
+        let package_commands = packages.iter().map(|&package| {
+            let commands = self.package_commands(
+                &args.channels,
+                &args.targets,
+                package,
+                on_save,
+                parallel,
+                &args.trailing_args,
+            );
+            (package, commands)
+        });
+
+        if parallel {
+            let mut all_commands = Vec::new();
+            let mut command_counts = Vec::new();
+
+            for (package, commands) in package_commands {
+                command_counts.push((package, commands.len()));
+                all_commands.extend(commands);
             }
 
-            for &package in packages {
-                let mut writer = PackageCacheWriter::new(package, msg_fmt_json);
+            let mut outputs = run_concurrently(all_commands, jobs).into_iter();
 
-                for &channel in channels {
-                    // The base command for `test`
-                    let mut command = self.base_command(channel);
+            for (package, count) in command_counts {
+                let mut writer = PackageCacheWriter::new(package, msg_fmt_json, errors_only);
 
-                    // Output to the corresponding cache file
-                    command.stderr(Stdio::piped());
+                for output in outputs.by_ref().take(count) {
+                    writer.cache_and_print_output(output);
+                }
 
-                    // Normal flags
-                    command.args(package.flags(channel, &Target::Native));
+                writer.print_summary();
+            }
 
-                    command.args(extra_args);
+        } else {
+
+            for (package, commands) in package_commands {
+                let mut writer = PackageCacheWriter::new(package, msg_fmt_json, errors_only);
 
+                for mut command in commands {
                     let child = command
                         .spawn()
                         .expect("Failed to spawn a cargo command");
 
                     writer.cache_and_print(child);
                 }
+
+                writer.print_summary();
             }
+        }
+    }
+
+    /// The commands to run on a single package, in order.
+    ///
+    /// If `separate_target_dirs` is true, each command uses its own target directory.
+    fn package_commands<S: AsRef<OsStr>>(
+        self,
+        channels:             &[Channel],
+        targets:              &[Target],
+        package:              Package,
+        on_save:              bool,
+        separate_target_dirs: bool,
+        extra_args:           &[S],
+    ) -> Vec<Command> {
+        // Assume that `--message-format=json` is enabled if and only if
+        // `on_save` is true.
+        let msg_fmt_json = on_save;
 
+        let mut commands = Vec::new();
 
-        } else {
+        if self == Self::Test {
+            for &channel in channels {
+                // The base command for `test`
+                let mut command = self.base_command(channel);
+
+                if separate_target_dirs {
+                    command.env("CARGO_TARGET_DIR", target_dir(package, channel, &Target::Native));
+                }
 
-            for &package in packages {
-                let mut writer = PackageCacheWriter::new(package, msg_fmt_json);
+                // Output to the corresponding cache file
+                command.stderr(Stdio::piped());
 
-                for &channel in channels {
-                    for target in targets {
-                        // The base command for `check` or `clippy`
-                        let mut command = self.base_command(channel);
+                // Normal flags
+                command.args(package.flags(channel, &Target::Native));
 
-                        // Output to the corresponding cache file
-                        if msg_fmt_json {
-                            command.stdout(Stdio::piped());
-                        } else {
-                            command.stderr(Stdio::piped());
-                        }
+                command.args(extra_args);
 
-                        // Normal flags
-                        if let Some(target_triple) = target.target_triple() {
-                            command.args(["--target", target_triple]);
-                        }
-                        command.args(package.flags(channel, target));
+                commands.push(command);
+            }
+
+
+        } else {
 
-                        // Make it return stuff that rust-analyzer can parse,
-                        // and do less work in --feature-powerset
-                        if on_save {
-                            command.args(["--message-format=json", "--depth", "1"]);
-                        }
+            for &channel in channels {
+                for target in targets {
+                    // The base command for `check` or `clippy`
+                    let mut command = self.base_command(channel);
+
+                    if separate_target_dirs {
+                        command.env("CARGO_TARGET_DIR", target_dir(package, channel, target));
+                    }
 
-                        command.args(extra_args);
+                    // Output to the corresponding cache file
+                    if msg_fmt_json {
+                        command.stdout(Stdio::piped());
+                    } else {
+                        command.stderr(Stdio::piped());
+                    }
 
-                        let child = command
-                            .spawn()
-                            .expect("Failed to spawn a cargo command");
+                    // Normal flags
+                    if let Some(target_triple) = target.target_triple() {
+                        command.args(["--target", target_triple]);
+                    }
+                    command.args(package.flags(channel, target));
 
-                        writer.cache_and_print(child);
+                    // Make it return stuff that rust-analyzer can parse,
+                    // and do less work in --feature-powerset
+                    if on_save {
+                        command.args(["--message-format=json", "--depth", "1"]);
                     }
+
+                    command.args(extra_args);
+
+                    commands.push(command);
                 }
             }
         }
+
+        commands
     }
 
     pub fn base_command(self, channel: Channel) -> Command {
@@ -122,6 +194,7 @@ impl CargoCommand {
         command.env("RUSTFLAGS", self.rust_flags(channel));
         let channel_arg = match channel {
             Channel::Stable     => "+stable",
+            Channel::Beta       => "+beta",
             Channel::Nightly    => "+nightly",
             Channel::StableMSRV => "+1.85",
         };
@@ -137,7 +210,7 @@ impl CargoCommand {
 
     pub const fn rust_flags(self, channel: Channel) -> &'static str {
         match (self, channel) {
-            (_, Channel::Stable | Channel::StableMSRV) => "",
+            (_, Channel::Stable | Channel::Beta | Channel::StableMSRV) => "",
             (Self::Check | Self::Test, Channel::Nightly) => "-Zpolonius",
             (Self::Clippy, Channel::Nightly) => "\
                 -Zpolonius \
@@ -160,3 +233,49 @@ impl CargoCommand {
         }
     }
 }
+
+/// The target directory used for a command run on the given package, channel, and target, when
+/// commands are run concurrently.
+fn target_dir(package: Package, channel: Channel, target: &Target) -> PathBuf {
+    Path::new("target/check-jobs")
+        .join(package.package_name())
+        .join(channel.name())
+        .join(target.target_triple().unwrap_or("native"))
+}
+
+/// Run up to `jobs` of the given commands at a time, and return their outputs in the same order
+/// as the commands.
+///
+/// May panic.
+fn run_concurrently(commands: Vec<Command>, jobs: NonZeroUsize) -> Vec<Output> {
+    let num_commands = commands.len();
+    let queue = Mutex::new(commands.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(num_commands) {
+            let sender = sender.clone();
+            let queue = &queue;
+
+            scope.spawn(move || {
+                loop {
+                    // Only hold the lock while taking the next command.
+                    let next = queue.lock().unwrap().next();
+                    let Some((index, mut command)) = next else { break };
+
+                    let output = command
+                        .output()
+                        .expect("Failed to spawn a cargo command");
+
+                    sender.send((index, output)).unwrap();
+                }
+            });
+        }
+    });
+
+    drop(sender);
+
+    let mut outputs = receiver.into_iter().collect::<Vec<_>>();
+    outputs.sort_by_key(|&(index, _)| index);
+    outputs.into_iter().map(|(_, output)| output).collect()
+}
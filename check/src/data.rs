@@ -6,27 +6,38 @@ use anyhow::anyhow;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Channel {
     Stable,
+    Beta,
     Nightly,
     StableMSRV,
 }
 
 impl Channel {
     pub const fn all_channels() -> &'static [Self] {
-        &[Self::Stable, Self::Nightly, Self::StableMSRV]
+        &[Self::Stable, Self::Beta, Self::Nightly, Self::StableMSRV]
     }
 
     pub const fn default_channels() -> &'static [Self] {
-        Self::all_channels()
+        &[Self::Stable, Self::Nightly, Self::StableMSRV]
     }
 
     pub fn parse(channel: &str) -> anyhow::Result<Self> {
         Ok(match channel {
             "stable"  => Self::Stable,
+            "beta"    => Self::Beta,
             "nightly" => Self::Nightly,
             "msrv"    => Self::StableMSRV,
             _ => return Err(anyhow!("Unknown channel name: {channel}")),
         })
     }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Stable     => "stable",
+            Self::Beta       => "beta",
+            Self::Nightly    => "nightly",
+            Self::StableMSRV => "msrv",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
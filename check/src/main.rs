@@ -8,8 +8,9 @@
 //!
 //! - Possible commands:
 //!   `check`, `clippy`, `test`. Note that `clippy` runs a superset of the checks that `check` does.
-//! - Possible channels: `stable`, `nightly`, `msrv`. (`beta` is not supported.) `msrv` refers to
-//!   the stable channel of the minimum-supported Rust version.
+//! - Possible channels: `stable`, `beta`, `nightly`, `msrv`. `msrv` refers to the stable channel
+//!   of the minimum-supported Rust version. `beta` is only used if requested with `--channel beta`
+//!   or `--all-channels`, and is otherwise not one of the default channels.
 //! - Possible targets:
 //!   `native` (the platform the compiler is run on),
 //!   `apple` or `apple-silicon`,
@@ -40,6 +41,13 @@
 //!   of 1 (making it equivalent to `--each-feature`), for use as an on-save check.
 //! - `--no-cache`:
 //!   Ignore previously cached outputs.
+//! - `--errors-only`:
+//!   Only print errors (and cargo's error summary lines), followed by a count of errors and
+//!   warnings for each package. The full output is still cached.
+//! - `--jobs {n}`:
+//!   Run up to `n` cargo commands concurrently (default 1). Each concurrently-run command uses
+//!   its own target directory under `target/check-jobs`, and output is buffered so that it is
+//!   printed and cached in the same order as when running sequentially.
 //! - `-- {trailing-arg}*`:
 //!   Pass any following arguments to the inner command
 //!   (`cargo hack check` or `cargo hack clippy`).
@@ -47,6 +55,7 @@
 mod data;
 mod commands;
 mod package_cache;
+mod message_filter;
 mod parsing;
 
 
@@ -67,14 +76,8 @@ fn main() -> anyhow::Result<()> {
     );
 
     // Check those
-    for command in args.commands {
-        command.run(
-            &args.channels,
-            &args.targets,
-            &to_check,
-            args.on_save,
-            &args.trailing_args,
-        );
+    for &command in &args.commands {
+        command.run(&args, &to_check);
     }
 
     // Print to stdour or stderr
@@ -83,6 +86,7 @@ fn main() -> anyhow::Result<()> {
         &to_check,
         args.on_save,
         args.no_cache,
+        args.errors_only,
     );
 
     Ok(())
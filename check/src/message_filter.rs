@@ -0,0 +1,138 @@
+use crate::data::Package;
+
+
+/// Rough severity of a line of cargo output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Error,
+    Warning,
+    Other,
+}
+
+/// Decides which lines of cargo output should be printed when `--errors-only` is used, and
+/// counts the errors and warnings seen.
+///
+/// Every line is still written to a package's cache; only what is printed is filtered.
+#[derive(Debug, Clone, Copy)]
+pub struct MessageFilter {
+    msg_fmt_json: bool,
+    errors_only:  bool,
+    errors:       usize,
+    warnings:     usize,
+}
+
+impl MessageFilter {
+    pub const fn new(msg_fmt_json: bool, errors_only: bool) -> Self {
+        Self {
+            msg_fmt_json,
+            errors_only,
+            errors:   0,
+            warnings: 0,
+        }
+    }
+
+    /// Count the error or warning in `line`, if any, and return whether `line` should be printed.
+    ///
+    /// In JSON mode, only `compiler-message` lines are considered to be errors or warnings.
+    /// In ANSI mode, lines starting with `error` or `warning` are, excluding cargo's summary
+    /// lines (like ``warning: `package` (lib) generated 2 warnings``); since nothing is
+    /// deduplicated in ANSI mode, the same warning may be counted once per channel and target.
+    pub fn filter(&mut self, line: &str) -> bool {
+        let level = if self.msg_fmt_json {
+            json_level(line)
+        } else {
+            ansi_level(line)
+        };
+
+        match level {
+            Level::Error   => self.errors   += 1,
+            Level::Warning => self.warnings += 1,
+            Level::Other   => {}
+        }
+
+        if self.msg_fmt_json {
+            !self.errors_only || level == Level::Error
+        } else {
+            // Also print cargo's summary lines like `error: could not compile`.
+            !self.errors_only || strip_ansi(line).starts_with("error")
+        }
+    }
+
+    /// If `--errors-only` was used, print the number of errors and warnings seen to stderr.
+    pub fn print_summary(&self, package: Package) {
+        if self.errors_only {
+            eprintln!(
+                "{}: {} errors, {} warnings",
+                package.package_name(),
+                self.errors,
+                self.warnings,
+            );
+        }
+    }
+}
+
+/// The level of a `compiler-message` line output with `--message-format=json`.
+fn json_level(line: &str) -> Level {
+    if !line.starts_with(r#"{"reason":"compiler-message""#) {
+        return Level::Other;
+    }
+
+    // The diagnostic's own `level` is serialized before its `children` (which have their own
+    // levels), and any quotes in its `message` or `rendered` strings are escaped, so the first
+    // `"level":` key is the diagnostic's level.
+    match line.split_once(r#""level":""#) {
+        Some((_, rest)) if rest.starts_with("error") => Level::Error,
+        Some((_, rest)) if rest.starts_with("warning") => Level::Warning,
+        _ => Level::Other,
+    }
+}
+
+/// The level of a line of human-readable (and possibly colored) output.
+fn ansi_level(line: &str) -> Level {
+    let line = strip_ansi(line);
+
+    if line.starts_with("error[") || line.starts_with("error:") {
+        let is_summary = [
+            "error: could not compile",
+            "error: aborting due to",
+            "error: process didn't exit successfully",
+        ]
+            .iter()
+            .any(|prefix| line.starts_with(prefix));
+
+        if is_summary { Level::Other } else { Level::Error }
+
+    } else if line.starts_with("warning:") {
+        let is_summary = line.starts_with("warning: `") && line.contains(" generated ")
+            || line.contains(" warning emitted")
+            || line.contains(" warnings emitted");
+
+        if is_summary { Level::Other } else { Level::Warning }
+
+    } else {
+        Level::Other
+    }
+}
+
+/// Remove ANSI escape sequences (as used by `--color always`) from `line`.
+fn strip_ansi(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip a CSI sequence like `\x1b[1;31m`, up to and including its final byte.
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
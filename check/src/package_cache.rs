@@ -1,12 +1,12 @@
 use std::{fs, io};
-use std::{fs::File, process::Child};
+use std::{fs::File, process::{Child, Output}};
 use std::{
     collections::{HashSet, VecDeque},
     io::{BufRead, BufReader, BufWriter, Write},
     path::{Path, PathBuf},
 };
 
-use crate::data::Package;
+use crate::{data::Package, message_filter::MessageFilter};
 
 
 const CHECK_CACHE_DIR: &str = ".check-cache";
@@ -14,14 +14,16 @@ const CHECK_CACHE_DIR: &str = ".check-cache";
 
 #[derive(Debug)]
 pub struct PackageCacheWriter {
+    package:       Package,
     file:          BufWriter<File>,
     msg_fmt_json:  bool,
     messages_seen: HashSet<String>,
+    filter:        MessageFilter,
 }
 
 impl PackageCacheWriter {
     /// May panic.
-    pub fn new(package: Package, msg_fmt_json: bool) -> Self {
+    pub fn new(package: Package, msg_fmt_json: bool, errors_only: bool) -> Self {
         fs::create_dir_all(CHECK_CACHE_DIR)
             .expect("Could not create `CHECK_CACHE_DIR`");
 
@@ -29,9 +31,11 @@ impl PackageCacheWriter {
 
         File::create(&package_cache)
             .map(|file| Self {
+                package,
                 file:          BufWriter::new(file),
                 msg_fmt_json,
                 messages_seen: HashSet::new(),
+                filter:        MessageFilter::new(msg_fmt_json, errors_only),
             })
             .expect("Could not create cache file for a certain package")
     }
@@ -44,23 +48,8 @@ impl PackageCacheWriter {
                 .take()
                 .expect("When `msg_fmt_json`, the child's stdout should be piped");
 
-            let reader = BufReader::new(stdout);
-
-            for line in reader.lines() {
-                let line = line.unwrap();
-
-                if line.starts_with(r#"{"reason":"compiler-message""#) {
-                    if !self.messages_seen.contains(&line) {
-                        self.file.write_all(line.as_bytes()).unwrap();
-                        self.file.write_all(b"\n").unwrap();
-                        println!("{line}");
-                        self.messages_seen.insert(line);
-                    }
-                } else {
-                    self.file.write_all(line.as_bytes()).unwrap();
-                    self.file.write_all(b"\n").unwrap();
-                    println!("{line}");
-                }
+            for line in BufReader::new(stdout).lines() {
+                self.cache_and_print_line(line.unwrap());
             }
         } else {
             let stderr = child
@@ -68,14 +57,8 @@ impl PackageCacheWriter {
                 .take()
                 .expect("When `!msg_fmt_json`, the child's stderr should be piped");
 
-            let reader = BufReader::new(stderr);
-
-            for line in reader.lines() {
-                let line = line.unwrap();
-
-                self.file.write_all(line.as_bytes()).unwrap();
-                self.file.write_all(b"\n").unwrap();
-                eprintln!("{line}");
+            for line in BufReader::new(stderr).lines() {
+                self.cache_and_print_line(line.unwrap());
             }
         }
 
@@ -87,6 +70,68 @@ impl PackageCacheWriter {
             panic!("A cargo command exited with unsuccesful status {exit_status}");
         }
     }
+
+    /// Like [`PackageCacheWriter::cache_and_print`], but for a child which already finished
+    /// and had both its stdout and stderr piped and buffered.
+    ///
+    /// The stream which would not have been piped by `cache_and_print` is printed, but not
+    /// cached, after the cached stream.
+    ///
+    /// May panic.
+    pub fn cache_and_print_output(&mut self, output: Output) {
+        let (cached, uncached) = if self.msg_fmt_json {
+            (output.stdout, output.stderr)
+        } else {
+            (output.stderr, output.stdout)
+        };
+
+        for line in cached.lines() {
+            self.cache_and_print_line(line.unwrap());
+        }
+
+        if self.msg_fmt_json {
+            io::stderr().write_all(&uncached).unwrap();
+        } else {
+            io::stdout().write_all(&uncached).unwrap();
+        }
+
+        if !output.status.success() {
+            panic!("A cargo command exited with unsuccesful status {}", output.status);
+        }
+    }
+
+    /// If `--errors-only` was used, print the number of errors and warnings seen.
+    pub fn print_summary(&self) {
+        self.filter.print_summary(self.package);
+    }
+
+    /// May panic.
+    fn cache_and_print_line(&mut self, line: String) {
+        if self.msg_fmt_json {
+            if line.starts_with(r#"{"reason":"compiler-message""#) {
+                if !self.messages_seen.contains(&line) {
+                    self.file.write_all(line.as_bytes()).unwrap();
+                    self.file.write_all(b"\n").unwrap();
+                    if self.filter.filter(&line) {
+                        println!("{line}");
+                    }
+                    self.messages_seen.insert(line);
+                }
+            } else {
+                self.file.write_all(line.as_bytes()).unwrap();
+                self.file.write_all(b"\n").unwrap();
+                if self.filter.filter(&line) {
+                    println!("{line}");
+                }
+            }
+        } else {
+            self.file.write_all(line.as_bytes()).unwrap();
+            self.file.write_all(b"\n").unwrap();
+            if self.filter.filter(&line) {
+                eprintln!("{line}");
+            }
+        }
+    }
 }
 
 /// Determine which packages need to be rechecked.
@@ -153,6 +198,7 @@ pub fn print_cached_checks(
     checked_packages: &[Package],
     on_save:          bool,
     no_cache:         bool,
+    errors_only:      bool,
 ) {
     // Assume that `--message-format=json` is enabled if and only if
     // `on_save` is true.
@@ -175,11 +221,24 @@ pub fn print_cached_checks(
                     continue;
                 }
 
-                // Read from the package check output in the cache, write to the writer
-                io::copy(
-                    &mut read_cache_for_package(package, msg_fmt_json),
-                    $writer,
-                ).unwrap();
+                let cache = read_cache_for_package(package, msg_fmt_json);
+
+                if errors_only {
+                    // Filter the cached lines, and write the ones to print to the writer
+                    let mut filter = MessageFilter::new(msg_fmt_json, errors_only);
+
+                    for line in BufReader::new(cache).lines() {
+                        let line = line.unwrap();
+                        if filter.filter(&line) {
+                            writeln!($writer, "{line}").unwrap();
+                        }
+                    }
+
+                    filter.print_summary(package);
+                } else {
+                    // Read from the package check output in the cache, write to the writer
+                    io::copy(&mut { cache }, $writer).unwrap();
+                }
             }
         };
     }
@@ -1,7 +1,7 @@
 use std::env;
-use std::collections::HashSet;
+use std::{collections::HashSet, num::NonZeroUsize};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context as _};
 
 use crate::{commands::CargoCommand, data::{Channel, Package, Target}};
 
@@ -14,6 +14,8 @@ pub struct ParsedArgs {
     pub packages:         Vec<Package>,
     pub on_save:          bool,
     pub no_cache:         bool,
+    pub errors_only:      bool,
+    pub jobs:             NonZeroUsize,
     pub trailing_args:    Vec<String>,
 }
 
@@ -44,6 +46,8 @@ impl ParsedArgs {
             packages: args_field_vec!(packages, all_packages, default_packages, Package),
             on_save:       raw_args.on_save,
             no_cache:      raw_args.no_cache,
+            errors_only:   raw_args.errors_only,
+            jobs:          raw_args.jobs.unwrap_or(NonZeroUsize::MIN),
             trailing_args: raw_args.trailing_args,
         })
     }
@@ -61,6 +65,8 @@ struct RawArgs {
     all_targets:      bool,
     all_packages:     bool,
     no_cache:         bool,
+    errors_only:      bool,
+    jobs:             Option<NonZeroUsize>,
     trailing_args:    Vec<String>,
 }
 
@@ -103,6 +109,17 @@ impl RawArgs {
 
                     raw_args.packages.insert(Package::parse(&next_arg)?);
                 }
+                "--jobs" => {
+                    let next_arg = input_args
+                        .next()
+                        .ok_or_else(|| anyhow!("Missing argument after `--jobs`"))?;
+
+                    let jobs = next_arg
+                        .parse()
+                        .with_context(|| format!("Invalid number of jobs: {next_arg}"))?;
+
+                    raw_args.jobs = Some(jobs);
+                }
                 "--all" => {
                     raw_args.all_commands = true;
                     raw_args.all_channels = true;
@@ -115,6 +132,7 @@ impl RawArgs {
                 "--all-packages"     => raw_args.all_packages = true,
                 "--on-save"          => raw_args.on_save      = true,
                 "--no-cache"         => raw_args.no_cache     = true,
+                "--errors-only"      => raw_args.errors_only  = true,
                 other => {
                     return Err(anyhow!(
                         "Unknown argument: {other} (maybe you meant to pass it after \"--\")",
@@ -0,0 +1,151 @@
+//! # Compile-Time Container Assertions
+//!
+//! Functions which compile if and only if a type implements one of the container traits.
+//! They do nothing at runtime, and are intended to be called in tests (or in `const` items) to
+//! pin down which container traits a type implements, so that a change to a container kind
+//! (for instance, from a non-[fragile] container to a fragile one) is caught at compile time.
+//!
+//! ## Examples
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use generic_container::assertions;
+//!
+//! const _: () = {
+//!     assertions::assert_mut_container::<u32, Box<u32>>();
+//!     assertions::assert_fragile_mut_container::<u32, Arc<Mutex<u32>>>();
+//!     assertions::assert_strict_container::<u32, Box<u32>>();
+//! };
+//! ```
+//!
+//! `Arc<Mutex<T>>` is fragile, so it is not a [`MutContainer<T>`]:
+//! ```compile_fail
+//! use std::sync::{Arc, Mutex};
+//! use generic_container::assertions;
+//!
+//! assertions::assert_mut_container::<u32, Arc<Mutex<u32>>>();
+//! ```
+//!
+//! Nor is `Arc<Mutex<T>>` a [`Container<T>`]:
+//! ```compile_fail
+//! use std::sync::{Arc, Mutex};
+//! use generic_container::assertions;
+//!
+//! assertions::assert_container::<u32, Arc<Mutex<u32>>>();
+//! ```
+//!
+//! `Arc<T>` cannot provide mutable access:
+//! ```compile_fail
+//! use std::sync::Arc;
+//! use generic_container::assertions;
+//!
+//! assertions::assert_fragile_try_mut_container::<u32, Arc<u32>>();
+//! ```
+//!
+//! Nor can `Arc<T>` always return its inner `T`, so it is not a [`StrictContainer<T>`]:
+//! ```compile_fail
+//! use std::sync::Arc;
+//! use generic_container::assertions;
+//!
+//! assertions::assert_strict_container::<u32, Arc<u32>>();
+//! ```
+//!
+//! [fragile]: crate#fragility-potential-panics-or-deadlocks
+//! [`MutContainer<T>`]: MutContainer
+//! [`Container<T>`]: Container
+//! [`StrictContainer<T>`]: StrictContainer
+
+use crate::container_traits::{
+    Container, FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    MutContainer, StrictContainer, TryContainer, TryMutContainer,
+};
+
+
+/// Compiles if and only if `C` implements [`FragileTryContainer<T>`].
+///
+/// [`FragileTryContainer<T>`]: FragileTryContainer
+#[inline]
+pub const fn assert_fragile_try_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + FragileTryContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`TryContainer<T>`].
+///
+/// [`TryContainer<T>`]: TryContainer
+#[inline]
+pub const fn assert_try_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + TryContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`FragileContainer<T>`].
+///
+/// [`FragileContainer<T>`]: FragileContainer
+#[inline]
+pub const fn assert_fragile_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + FragileContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`Container<T>`].
+///
+/// [`Container<T>`]: Container
+#[inline]
+pub const fn assert_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + Container<T>,
+{}
+
+/// Compiles if and only if `C` implements [`FragileTryMutContainer<T>`].
+///
+/// [`FragileTryMutContainer<T>`]: FragileTryMutContainer
+#[inline]
+pub const fn assert_fragile_try_mut_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + FragileTryMutContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`TryMutContainer<T>`].
+///
+/// [`TryMutContainer<T>`]: TryMutContainer
+#[inline]
+pub const fn assert_try_mut_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + TryMutContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`FragileMutContainer<T>`].
+///
+/// [`FragileMutContainer<T>`]: FragileMutContainer
+#[inline]
+pub const fn assert_fragile_mut_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + FragileMutContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`MutContainer<T>`].
+///
+/// [`MutContainer<T>`]: MutContainer
+#[inline]
+pub const fn assert_mut_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + MutContainer<T>,
+{}
+
+/// Compiles if and only if `C` implements [`StrictContainer<T>`].
+///
+/// [`StrictContainer<T>`]: StrictContainer
+#[inline]
+pub const fn assert_strict_container<T, C>()
+where
+    T: ?Sized,
+    C: ?Sized + StrictContainer<T>,
+{}
@@ -0,0 +1,173 @@
+//! # Async Container Traits
+//!
+//! Asynchronous counterparts of [`FragileContainer`] and [`FragileMutContainer`], for containers
+//! (such as `Arc<tokio::sync::Mutex<T>>`) which must `.await` before they can provide access to
+//! the `T` they contain.
+//!
+//! The traits here do not depend on any particular async runtime. Implementations for specific
+//! runtimes or lock crates are enabled by features.
+//!
+//! ## Fragility: Potential Deadlocks
+//!
+//! Async locks are generally not reentrant: if a task holds a guard obtained from an async
+//! container and then awaits another borrow from the same container (or a clone referencing the
+//! same inner `T`), the task will never be woken. Async containers should be treated as
+//! [fragile], with "task" in place of "thread".
+//!
+//! ## Receivers and Object Safety
+//!
+//! As with [`FragileMutContainer::get_mut`], the mutable accessors of [`AsyncMutContainer`] take
+//! `&mut self` rather than `&self`. This keeps the async traits interchangeable with the sync
+//! ones in generic code, and permits implementations which provide mutable access without locking.
+//! The provided lock-based containers are `Clone`, so tasks which share a `T` can each mutate it
+//! through their own clone.
+//!
+//! The methods return `impl Future`, so these traits are not object safe, and cannot be used as
+//! `dyn AsyncContainer<T>`. Object safety would require boxing every returned future, which needs
+//! `alloc` and allocates on every borrow; use a generic `C: AsyncContainer<T>` parameter instead.
+//!
+//! ## Example
+//!
+//! ```
+//! use generic_container::AsyncMutContainer;
+//!
+//! async fn increment<C: AsyncMutContainer<u32>>(counter: &mut C) {
+//!     *counter.get_mut_async().await += 1;
+//! }
+//! ```
+//!
+//! [`FragileContainer`]: crate::FragileContainer
+//! [`FragileMutContainer`]: crate::FragileMutContainer
+//! [`FragileMutContainer::get_mut`]: crate::FragileMutContainer::get_mut
+//! [fragile]: crate#fragility-potential-panics-or-deadlocks
+
+use core::ops::{Deref, DerefMut};
+
+use crate::container_traits::ContainerError;
+
+
+/// An abstraction over some container which owns a `T` and can asynchronously provide immutable
+/// references to it, or be consumed to return the inner `T` (if `T` is [`Sized`]).
+///
+/// This is the asynchronous analogue of [`FragileContainer`], and should likewise be treated
+/// as [fragile].
+///
+/// The futures returned by this trait's methods are [`Send`], so that generic code can be spawned
+/// onto multithreaded executors.
+///
+/// [`FragileContainer`]: crate::FragileContainer
+/// [fragile]: crate::async_container#fragility-potential-deadlocks
+pub trait AsyncContainer<T: ?Sized> {
+    /// An immutably borrowed value from the container.
+    ///
+    /// May have a nontrivial `Drop` implementatation, as with the guard types of async locks.
+    type Ref<'a>:  Deref<Target = T> where Self: 'a;
+    /// An error that might be returned by [`try_get_ref_async`].
+    ///
+    /// The canonical error to use when [`try_get_ref_async`] can never return an error
+    /// is [`Infallible`].
+    ///
+    /// [`try_get_ref_async`]: AsyncContainer::try_get_ref_async
+    /// [`Infallible`]: core::convert::Infallible
+    type RefError: ContainerError;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    ///
+    /// The same note for implementors as [`FragileTryContainer::into_inner`] applies.
+    ///
+    /// [`FragileTryContainer::into_inner`]: crate::FragileTryContainer::into_inner
+    #[must_use = "the contained value is dropped if the result is discarded"]
+    fn into_inner(self) -> Option<T> where Self: Sized, T: Sized;
+
+    /// Attempt to immutably access the inner `T`, waiting until it is available.
+    ///
+    /// # Fragility: Potential Deadlocks
+    ///
+    /// The returned future may never complete if the current task already has a reference to the
+    /// inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Errors
+    ///
+    /// Errors are implementation-defined, and should be documented by implementors.
+    fn try_get_ref_async(
+        &self,
+    ) -> impl Future<Output = Result<Self::Ref<'_>, Self::RefError>> + Send;
+
+    /// Immutably borrow the inner `T`, waiting until it is available.
+    ///
+    /// # Fragility: Potential Deadlocks
+    ///
+    /// The returned future may never complete if the current task already has a reference to the
+    /// inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate::async_container#fragility-potential-deadlocks).
+    fn get_ref_async(&self) -> impl Future<Output = Self::Ref<'_>> + Send;
+}
+
+/// An [`AsyncContainer`] which can be created from the `T` that it should contain.
+///
+/// This is the asynchronous analogue of [`ContainerNew`]. As there, creating a container is kept
+/// separate from accessing it, so that containers which cannot be constructed from a `T`, such as
+/// borrowing or type-erased containers, can still implement [`AsyncContainer`].
+///
+/// [`ContainerNew`]: crate::ContainerNew
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "pairs with `AsyncContainer`, and is used through the crate root",
+)]
+pub trait AsyncContainerNew<T: ?Sized>: AsyncContainer<T> {
+    /// Create a new container that owns the provided `T`.
+    #[must_use]
+    fn new_container(t: T) -> Self where Self: Sized, T: Sized;
+}
+
+/// An abstraction over some container which owns a `T` and can asynchronously provide mutable or
+/// immutable references to it, or be consumed to return the inner `T` (if `T` is [`Sized`]).
+///
+/// This is the asynchronous analogue of [`FragileMutContainer`], and should likewise be treated
+/// as [fragile].
+///
+/// [`FragileMutContainer`]: crate::FragileMutContainer
+/// [fragile]: crate::async_container#fragility-potential-deadlocks
+pub trait AsyncMutContainer<T: ?Sized>: AsyncContainer<T> {
+    /// A mutably borrowed value from the container.
+    ///
+    /// May have a nontrivial `Drop` implementatation, as with the guard types of async locks.
+    type RefMut<'a>:  DerefMut<Target = T> where Self: 'a;
+    /// An error that might be returned by [`try_get_mut_async`].
+    ///
+    /// The canonical error to use when [`try_get_mut_async`] can never return an error
+    /// is [`Infallible`].
+    ///
+    /// [`try_get_mut_async`]: AsyncMutContainer::try_get_mut_async
+    /// [`Infallible`]: core::convert::Infallible
+    type RefMutError: ContainerError;
+
+    /// Attempt to mutably access the inner `T`, waiting until it is available.
+    ///
+    /// # Fragility: Potential Deadlocks
+    ///
+    /// The returned future may never complete if the current task already has a reference to the
+    /// inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Errors
+    ///
+    /// Errors are implementation-defined, and should be documented by implementors.
+    fn try_get_mut_async(
+        &mut self,
+    ) -> impl Future<Output = Result<Self::RefMut<'_>, Self::RefMutError>> + Send;
+
+    /// Mutably borrow the inner `T`, waiting until it is available.
+    ///
+    /// # Fragility: Potential Deadlocks
+    ///
+    /// The returned future may never complete if the current task already has a reference to the
+    /// inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate::async_container#fragility-potential-deadlocks).
+    fn get_mut_async(&mut self) -> impl Future<Output = Self::RefMut<'_>> + Send;
+}
@@ -0,0 +1,217 @@
+//! # Atomic Container Trait
+//!
+//! A container trait for lock-free containers of [`Copy`] primitives, such as `Arc<AtomicU64>`.
+//!
+//! Atomics cannot provide references to the value they contain, so they cannot implement
+//! [`FragileContainer`] or [`FragileMutContainer`]. Instead, the value is read and written as a
+//! whole with [`load`] and [`store`], or updated with [`compare_exchange`], each with an explicit
+//! memory [`Ordering`].
+//!
+//! Atomic containers never block, and so are never
+//! [fragile](crate#fragility-potential-panics-or-deadlocks).
+//!
+//! ## Example
+//!
+//! ```
+//! use std::sync::{Arc, atomic::{AtomicU64, Ordering}};
+//! use generic_container::AtomicContainer;
+//!
+//! fn bump<C: AtomicContainer<u64>>(counter: &C) {
+//!     let mut current = counter.load(Ordering::Relaxed);
+//!     // A `load` followed by a `store` could lose increments made by other threads in between.
+//!     while let Err(actual) = counter.compare_exchange(
+//!         current,
+//!         current + 1,
+//!         Ordering::Relaxed,
+//!         Ordering::Relaxed,
+//!     ) {
+//!         current = actual;
+//!     }
+//! }
+//!
+//! let counter = Arc::new(AtomicU64::new(0));
+//! std::thread::scope(|scope| {
+//!     for _ in 0..4 {
+//!         scope.spawn(|| bump(&counter));
+//!     }
+//! });
+//! assert_eq!(counter.load(Ordering::Relaxed), 4);
+//! ```
+//!
+//! [`FragileContainer`]: crate::FragileContainer
+//! [`FragileMutContainer`]: crate::FragileMutContainer
+//! [`load`]: AtomicContainer::load
+//! [`store`]: AtomicContainer::store
+//! [`compare_exchange`]: AtomicContainer::compare_exchange
+
+#[cfg(any(feature = "alloc", doc))]
+use alloc::sync::Arc;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+use core::sync::atomic::Ordering;
+
+
+/// An abstraction over some container which owns a [`Copy`] primitive `T`, and can atomically
+/// read or write it without locking.
+///
+/// Implemented for the atomic integer types and [`AtomicBool`] of `core::sync::atomic`, and for
+/// an `Arc` around any atomic container.
+pub trait AtomicContainer<T: Copy> {
+    /// Atomically load the inner `T`.
+    ///
+    /// # Panics
+    /// May panic if `order` is [`Release`] or [`AcqRel`], as with the `load` methods of the
+    /// atomic types.
+    ///
+    /// [`Release`]: Ordering::Release
+    /// [`AcqRel`]: Ordering::AcqRel
+    #[must_use]
+    fn load(&self, order: Ordering) -> T;
+
+    /// Atomically replace the inner `T` with `value`.
+    ///
+    /// # Panics
+    /// May panic if `order` is [`Acquire`] or [`AcqRel`], as with the `store` methods of the
+    /// atomic types.
+    ///
+    /// [`Acquire`]: Ordering::Acquire
+    /// [`AcqRel`]: Ordering::AcqRel
+    fn store(&self, value: T, order: Ordering);
+
+    /// Atomically replace the inner `T` with `new` if it equals `current`, returning the previous
+    /// value.
+    ///
+    /// `success` is the ordering used if the replacement takes place, and `failure` is the ordering
+    /// of the load used if it does not.
+    ///
+    /// # Errors
+    /// If the inner `T` did not equal `current`, it is left in place and returned in [`Err`].
+    ///
+    /// # Panics
+    /// May panic if `failure` is [`Release`] or [`AcqRel`], as with the `compare_exchange`
+    /// methods of the atomic types.
+    ///
+    /// [`Release`]: Ordering::Release
+    /// [`AcqRel`]: Ordering::AcqRel
+    fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T>;
+}
+
+/// An [`AtomicContainer`] which can be created from the `T` that it should contain.
+///
+/// As with [`ContainerNew`], creating a container is kept separate from accessing it, so that
+/// containers which cannot be constructed from a `T` can still implement [`AtomicContainer`].
+///
+/// [`ContainerNew`]: crate::ContainerNew
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "pairs with `AtomicContainer`, and is used through the crate root",
+)]
+pub trait AtomicContainerNew<T: Copy>: AtomicContainer<T> {
+    /// Create a new container that owns the provided `T`.
+    #[must_use]
+    fn new_container(value: T) -> Self where Self: Sized;
+}
+
+/// Implements `AtomicContainer<$prim>` and `AtomicContainerNew<$prim>` for `$atomic` by delegating
+/// to its inherent methods.
+macro_rules! impl_atomic_container {
+    ($($width:literal: $atomic:ident($prim:ty)),* $(,)?) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicContainer<$prim> for $atomic {
+            /// Behaves identically to the inherent `load` method.
+            #[inline]
+            fn load(&self, order: Ordering) -> $prim {
+                Self::load(self, order)
+            }
+
+            /// Behaves identically to the inherent `store` method.
+            #[inline]
+            fn store(&self, value: $prim, order: Ordering) {
+                Self::store(self, value, order);
+            }
+
+            /// Behaves identically to the inherent `compare_exchange` method.
+            #[inline]
+            fn compare_exchange(
+                &self,
+                current: $prim,
+                new: $prim,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$prim, $prim> {
+                Self::compare_exchange(self, current, new, success, failure)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicContainerNew<$prim> for $atomic {
+            #[inline]
+            fn new_container(value: $prim) -> Self {
+                Self::new(value)
+            }
+        }
+    )*};
+}
+
+impl_atomic_container! {
+    "8":   AtomicBool(bool),
+    "8":   AtomicU8(u8),
+    "8":   AtomicI8(i8),
+    "16":  AtomicU16(u16),
+    "16":  AtomicI16(i16),
+    "32":  AtomicU32(u32),
+    "32":  AtomicI32(i32),
+    "64":  AtomicU64(u64),
+    "64":  AtomicI64(i64),
+    "ptr": AtomicUsize(usize),
+    "ptr": AtomicIsize(isize),
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T: Copy, C: AtomicContainer<T>> AtomicContainer<T> for Arc<C> {
+    /// Loads the `T` from the shared atomic container.
+    #[inline]
+    fn load(&self, order: Ordering) -> T {
+        (**self).load(order)
+    }
+
+    /// Stores `value` into the shared atomic container.
+    #[inline]
+    fn store(&self, value: T, order: Ordering) {
+        (**self).store(value, order);
+    }
+
+    /// Compares and exchanges the `T` in the shared atomic container.
+    #[inline]
+    fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        (**self).compare_exchange(current, new, success, failure)
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T: Copy, C: AtomicContainerNew<T>> AtomicContainerNew<T> for Arc<C> {
+    #[inline]
+    fn new_container(value: T) -> Self {
+        Self::new(C::new_container(value))
+    }
+}
@@ -0,0 +1,107 @@
+//! # Container Construction Helpers
+//!
+//! Shorthands for creating containers of collections directly from iterators, or from the
+//! contents of another container, which is particularly convenient when the container type is a
+//! generic parameter or a [container kind](crate::kinds)'s associated type.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::{rc::Rc, sync::{Arc, Mutex}};
+//! use generic_container::build;
+//!
+//! let evens: Rc<Vec<u32>> = build::collect_container((0..5).map(|n| n * 2));
+//! assert_eq!(*evens, [0, 2, 4, 6, 8]);
+//!
+//! let word = build::collect_string_container::<Arc<Mutex<String>>, _, _>(['h', 'i']);
+//! assert_eq!(*word.lock().unwrap(), "hi");
+//! ```
+
+#[cfg(any(feature = "alloc", doc))]
+use alloc::{string::String, vec::Vec};
+
+use crate::container_traits::{ContainerNew, FragileTryContainer};
+
+
+/// Moves the inner `T` out of `src` with [`into_inner`], and creates a new container `Dst`
+/// holding it.
+///
+/// This migrates data between different sorts of containers, such as from a shared
+/// `Arc<Mutex<T>>` into a uniquely-owned `Box<T>`.
+///
+/// Returns `None` if `src` could not be consumed to return its `T`, for instance because other
+/// clones of an `Rc`- or `Arc`-based container still exist. Since [`into_inner`] consumes the
+/// container, `src` cannot be returned in that case; only the calling container's handle is
+/// dropped, and the `T` remains owned by the other clones. To fall back to cloning the `T`
+/// instead, see [`IntoInnerOrClone`].
+///
+/// ## Examples
+/// ```
+/// use std::sync::Arc;
+/// use generic_container::build;
+/// # #[cfg(feature = "kinds")] {
+/// use generic_container::kinds::{ArcKind, ArcLike, BoxKind, BoxLike};
+///
+/// fn unshare<S, D>(shared: S::Container<String>) -> Option<D::Container<String>>
+/// where
+///     S: ArcLike,
+///     D: BoxLike,
+/// {
+///     build::transfer::<String, _, _>(shared)
+/// }
+///
+/// let shared = ArcKind::wrap("moved".to_owned());
+/// let clone = Arc::clone(&shared);
+///
+/// // Another clone exists, so the `String` cannot be moved out.
+/// assert!(unshare::<ArcKind, BoxKind>(clone).is_none());
+///
+/// let boxed = unshare::<ArcKind, BoxKind>(shared).unwrap();
+/// assert_eq!(*boxed, "moved");
+/// # }
+/// ```
+///
+/// [`into_inner`]: FragileTryContainer::into_inner
+/// [`IntoInnerOrClone`]: crate::IntoInnerOrClone
+#[inline]
+#[must_use]
+pub fn transfer<T, Src, Dst>(src: Src) -> Option<Dst>
+where
+    Src: FragileTryContainer<T>,
+    Dst: ContainerNew<T>,
+{
+    src.into_inner().map(Dst::new_container)
+}
+
+/// Collects the items of `iter` into a [`Vec`], and creates a container `C` holding it.
+///
+/// Equivalent to `C::new_container(iter.into_iter().collect())`.
+#[cfg(any(feature = "alloc", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[inline]
+#[must_use]
+pub fn collect_container<C, U, I>(iter: I) -> C
+where
+    C: ContainerNew<Vec<U>>,
+    I: IntoIterator<Item = U>,
+{
+    C::new_container(iter.into_iter().collect())
+}
+
+/// Collects the items of `iter` into a [`String`], and creates a container `C` holding it.
+///
+/// Any item type that a `String` can be collected from (such as `char` or `&str`) may be used.
+///
+/// Equivalent to `C::new_container(iter.into_iter().collect())`.
+#[cfg(any(feature = "alloc", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[inline]
+#[must_use]
+pub fn collect_string_container<C, U, I>(iter: I) -> C
+where
+    C: ContainerNew<String>,
+    I: IntoIterator<Item = U>,
+    String: FromIterator<U>,
+{
+    C::new_container(iter.into_iter().collect())
+}
@@ -0,0 +1,283 @@
+#![warn(
+    clippy::missing_inline_in_public_items,
+    reason = "the wrapper type should mostly just delegate",
+)]
+
+use core::{cmp::Ordering, marker::PhantomData};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::container_traits::{Container, FragileContainer};
+use crate::generic_container::GenericContainer;
+
+
+// Default, Debug, Copy, and Clone are manually implemented and defer to the container, while
+// PartialEq, Eq, PartialOrd, Ord, and Hash use the contents of containers.
+/// A wrapper type around a container `C` holding a `T`, which compares and hashes according to the
+/// `T` value inside the container.
+///
+/// This complements [`GenericContainer`], whose comparison traits defer to the container
+/// itself. For instance, two `Rc<RefCell<T>>` containers compare their contents, but two
+/// `Arc<Mutex<T>>` containers cannot be compared at all; as `Contained` values, both can be
+/// compared by their contents. Likewise, `Contained` values can be used as the keys of a map
+/// keyed by the inner values.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Comparing or hashing `Contained` values borrows each container with [`get_ref`]. If a
+/// container is [fragile] and the current thread already has a live borrow of its inner `T`
+/// (including the borrow made for the other operand, if both `Contained` values refer to the same
+/// inner `T`), then comparison or hashing may panic or deadlock. Using non-fragile containers
+/// (which implement [`Container<T>`]) avoids this risk.
+///
+/// ## Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use generic_container::Contained;
+///
+/// let first:  Contained<u8, Box<u8>> = Contained::new(Box::new(1));
+/// let second: Contained<u8, Rc<RefCell<u8>>> = Contained::new(Rc::new(RefCell::new(1)));
+///
+/// assert!(first == second);
+/// assert!(first.eq_inner(&1));
+/// ```
+///
+/// [`GenericContainer`]: crate::GenericContainer
+/// [`Container<T>`]: Container
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Contained<T: ?Sized, C: ?Sized> {
+    /// Distinguish which type is supposed to be contained.
+    pub _marker:   PhantomData<T>,
+    /// Should implement [`FragileContainer<T>`] in order to compare contents.
+    pub container: C,
+}
+
+impl<T: ?Sized, C> Contained<T, C> {
+    /// Create a new `Contained` struct wrapping the provided container, which is treated as a
+    /// container around a specific type.
+    #[inline]
+    #[must_use]
+    pub const fn new(container: C) -> Self {
+        Self {
+            _marker: PhantomData,
+            container,
+        }
+    }
+}
+
+impl<T: ?Sized, C: ?Sized + FragileContainer<T>> Contained<T, C> {
+    /// Compare the inner `T` of the container with an external value.
+    ///
+    /// A blanket `PartialEq<Rhs>` implementation would conflict with comparisons between
+    /// `Contained` values, so this method is provided instead.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// Borrows the container with [`get_ref`]; see the [type-level documentation](Contained).
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    #[inline]
+    #[must_use]
+    pub fn eq_inner<Rhs: ?Sized>(&self, other: &Rhs) -> bool
+    where
+        T: PartialEq<Rhs>,
+    {
+        *self.container.get_ref() == *other
+    }
+}
+
+impl<T: ?Sized, C: Default> Default for Contained<T, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<T, C> Debug for Contained<T, C>
+where
+    T: ?Sized,
+    C: ?Sized + Debug,
+{
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Contained")
+            .field("_marker", &self._marker)
+            .field("container", &&self.container)
+            .finish()
+    }
+}
+
+/// Formats the inner `T` of the container, borrowed with [`get_ref`].
+///
+/// This is only implemented for non-[fragile] containers, so formatting a `Contained` value
+/// cannot panic or deadlock due to an existing borrow of the container.
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T, C> Display for Contained<T, C>
+where
+    T: ?Sized + Display,
+    C: ?Sized + Container<T>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&*self.container.get_ref(), f)
+    }
+}
+
+impl<T: ?Sized, C: Copy> Copy for Contained<T, C> {}
+
+impl<T: ?Sized, C: Clone> Clone for Contained<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            _marker:   self._marker,
+            container: self.container.clone(),
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.container.clone_from(&source.container);
+    }
+}
+
+/// Compares the inner values of the containers, borrowed with [`get_ref`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// If either container is [fragile], comparison may panic or deadlock; see the
+/// [type-level documentation](Contained).
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T, C, U, D> PartialEq<Contained<U, D>> for Contained<T, C>
+where
+    T: ?Sized + PartialEq<U>,
+    C: ?Sized + FragileContainer<T>,
+    U: ?Sized,
+    D: ?Sized + FragileContainer<U>,
+{
+    #[inline]
+    fn eq(&self, other: &Contained<U, D>) -> bool {
+        *self.container.get_ref() == *other.container.get_ref()
+    }
+}
+
+impl<T: ?Sized + Eq, C: ?Sized + FragileContainer<T>> Eq for Contained<T, C> {}
+
+/// Compares the inner values of the containers, borrowed with [`get_ref`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// If either container is [fragile], comparison may panic or deadlock; see the
+/// [type-level documentation](Contained).
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T, C, U, D> PartialOrd<Contained<U, D>> for Contained<T, C>
+where
+    T: ?Sized + PartialOrd<U>,
+    C: ?Sized + FragileContainer<T>,
+    U: ?Sized,
+    D: ?Sized + FragileContainer<U>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Contained<U, D>) -> Option<Ordering> {
+        (*self.container.get_ref()).partial_cmp(&*other.container.get_ref())
+    }
+}
+
+/// Compares the inner values of the containers, borrowed with [`get_ref`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// If the containers are [fragile], comparison may panic or deadlock; see the
+/// [type-level documentation](Contained).
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T: ?Sized + Ord, C: ?Sized + FragileContainer<T>> Ord for Contained<T, C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self.container.get_ref()).cmp(&*other.container.get_ref())
+    }
+}
+
+/// Hashes the inner value of the container, borrowed with [`get_ref`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// If the container is [fragile], hashing may panic or deadlock; see the
+/// [type-level documentation](Contained).
+///
+/// ## Examples
+/// ```
+/// use std::collections::HashSet;
+/// use std::sync::Arc;
+/// use generic_container::Contained;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Contained::<u8, Arc<u8>>::new(Arc::new(1)));
+///
+/// assert!(set.contains(&Contained::new(Arc::new(1))));
+/// assert!(!set.contains(&Contained::new(Arc::new(2))));
+/// ```
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T: ?Sized + Hash, C: ?Sized + FragileContainer<T>> Hash for Contained<T, C> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.container.get_ref()).hash(state);
+    }
+}
+
+/// Rewrap a [`GenericContainer`] as a `Contained` value. This is a zero-cost move of the
+/// container.
+///
+/// Note that the two wrappers compare and hash differently: a `GenericContainer` defers to the
+/// container itself (so that, for instance, two `Arc<T>` containers are compared by their `T`
+/// values, but two `Arc<Mutex<T>>` containers cannot be compared at all), while a `Contained`
+/// value always compares and hashes the inner `T` borrowed from the container.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::{Contained, GenericContainer};
+///
+/// let container = GenericContainer::<u8, _>::new(Arc::new(Mutex::new(1)));
+/// let contained: Contained<u8, Arc<Mutex<u8>>> = container.into();
+/// assert!(contained.eq_inner(&1));
+/// ```
+///
+/// [`GenericContainer`]: crate::GenericContainer
+impl<T: ?Sized, C> From<GenericContainer<T, C>> for Contained<T, C> {
+    #[inline]
+    fn from(container: GenericContainer<T, C>) -> Self {
+        Self::new(container.container)
+    }
+}
+
+/// Rewrap a `Contained` value as a [`GenericContainer`]. This is a zero-cost move of the
+/// container.
+///
+/// Note that the two wrappers compare and hash differently: a `Contained` value always compares
+/// and hashes the inner `T` borrowed from the container, while a `GenericContainer` defers to the
+/// container itself, which may compare by identity or not be comparable at all.
+///
+/// [`GenericContainer`]: crate::GenericContainer
+impl<T: ?Sized, C> From<Contained<T, C>> for GenericContainer<T, C> {
+    #[inline]
+    fn from(contained: Contained<T, C>) -> Self {
+        Self::new(contained.container)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::container_traits::FragileContainer;
+
+
+/// Extension trait for convenience methods which borrow a container's inner `T`.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Each method borrows the container once with [`get_ref`], and holds the borrow while the
+/// provided closure runs. If the container is [fragile], then it must not already be borrowed by
+/// the current thread, and the closure must not borrow the same container.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::ContainerExt as _;
+///
+/// let container = Arc::new(Mutex::new(vec![1, 2, 3]));
+/// let mut seen = Vec::new();
+///
+/// let clone = Arc::clone(container.inspect(|list: &Vec<i32>| seen.push(list.len())));
+/// clone.lock().unwrap().push(4);
+/// container.inspect(|list: &Vec<i32>| seen.push(list.len()));
+///
+/// assert_eq!(seen, [3, 4]);
+/// ```
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait ContainerExt<T: ?Sized>: FragileContainer<T> {
+    /// Borrow the inner `T` with [`get_ref`] and call `f` on it, then return the container, so that
+    /// the contents can be observed (for instance, for logging) in the middle of a method chain.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    fn inspect<F: FnOnce(&T)>(&self, f: F) -> &Self;
+}
+
+impl<T: ?Sized, C: ?Sized + FragileContainer<T>> ContainerExt<T> for C {
+    #[inline]
+    fn inspect<F: FnOnce(&T)>(&self, f: F) -> &Self {
+        f(&self.get_ref());
+        self
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use crate::container_traits::{FragileContainer, FragileMutContainer};
+
+
+/// Extension trait for iterating over the items of a container of a [`Vec`], with a single borrow
+/// of the container.
+///
+/// Returning an iterator from a container would tie the iterator to a temporary [`Ref`] (such as a
+/// lock guard), so these methods take a callback instead.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Each method borrows the container once, with [`get_ref`] or [`get_mut`]. If the container is
+/// [fragile], then the callback must not borrow the same container.
+///
+/// ## Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use generic_container::ContainerIter as _;
+///
+/// let mut container = Rc::new(RefCell::new(vec![1, 2, 3]));
+///
+/// container.for_each_item_mut(|item| *item *= 2);
+///
+/// let mut sum = 0;
+/// container.for_each_item(|item| sum += item);
+/// assert_eq!(sum, 12);
+/// ```
+///
+/// [`Ref`]: crate::FragileTryContainer::Ref
+/// [`get_ref`]: FragileContainer::get_ref
+/// [`get_mut`]: FragileMutContainer::get_mut
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait ContainerIter<U>: FragileContainer<Vec<U>> {
+    /// Borrow the container once, and call `f` on each item of the inner `Vec`, in order.
+    fn for_each_item<F: FnMut(&U)>(&self, f: F);
+
+    /// Mutably borrow the container once, and call `f` on each item of the inner `Vec`, in order.
+    fn for_each_item_mut<F: FnMut(&mut U)>(&mut self, f: F)
+    where
+        Self: FragileMutContainer<Vec<U>>;
+}
+
+impl<U, C: ?Sized + FragileContainer<Vec<U>>> ContainerIter<U> for C {
+    #[inline]
+    fn for_each_item<F: FnMut(&U)>(&self, f: F) {
+        self.get_ref().iter().for_each(f);
+    }
+
+    #[inline]
+    fn for_each_item_mut<F: FnMut(&mut U)>(&mut self, f: F)
+    where
+        Self: FragileMutContainer<Vec<U>>,
+    {
+        self.get_mut().iter_mut().for_each(f);
+    }
+}
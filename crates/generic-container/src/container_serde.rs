@@ -0,0 +1,128 @@
+#![warn(
+    clippy::missing_inline_in_public_items,
+    reason = "the wrapper type should mostly just delegate",
+)]
+
+use core::marker::PhantomData;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::container_traits::{ContainerNew, FragileContainer};
+
+
+/// A wrapper type around a container `C` holding a `T`, which is serialized as the inner `T`
+/// and deserialized into a fresh container.
+///
+/// By contrast, the `Serialize` and `Deserialize` implementations of [`GenericContainer`] defer to
+/// the container itself. A `ContainerSerde` value is serialized by borrowing the inner `T` with
+/// [`get_ref`], and deserialized by deserializing a `T` and creating a new container around it
+/// with [`new_container`]. For instance, an `Arc<Mutex<T>>` is persisted as just its `T`, and
+/// a deserialized `ContainerSerde` shares its `T` with no other container.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Serializing a `ContainerSerde` value borrows the container with [`get_ref`]. If the container
+/// is [fragile] and the current thread already has a live borrow of its inner `T`, serialization
+/// may panic or deadlock.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use serde::{Deserialize as _, de::{value::{Error, U32Deserializer}, IntoDeserializer as _}};
+/// use generic_container::ContainerSerde;
+///
+/// let deserializer: U32Deserializer<Error> = 5_u32.into_deserializer();
+/// let shared = ContainerSerde::<u32, Arc<Mutex<u32>>>::deserialize(deserializer).unwrap();
+///
+/// assert_eq!(*shared.container.lock().unwrap(), 5);
+/// ```
+///
+/// [`GenericContainer`]: crate::GenericContainer
+/// [`get_ref`]: FragileContainer::get_ref
+/// [`new_container`]: ContainerNew::new_container
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+#[repr(transparent)]
+pub struct ContainerSerde<T: ?Sized, C: ?Sized> {
+    /// Distinguish which type is supposed to be contained.
+    pub _marker:   PhantomData<T>,
+    /// Should implement [`FragileContainer<T>`] in order to be serialized, and
+    /// [`ContainerNew<T>`] in order to be deserialized.
+    ///
+    /// [`FragileContainer<T>`]: FragileContainer
+    /// [`ContainerNew<T>`]: ContainerNew
+    pub container: C,
+}
+
+impl<T: ?Sized, C> ContainerSerde<T, C> {
+    /// Create a new `ContainerSerde` struct wrapping the provided container, which is treated as a
+    /// container around a specific type.
+    #[inline]
+    #[must_use]
+    pub const fn new(container: C) -> Self {
+        Self {
+            _marker: PhantomData,
+            container,
+        }
+    }
+}
+
+impl<T, C> Debug for ContainerSerde<T, C>
+where
+    T: ?Sized,
+    C: ?Sized + Debug,
+{
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ContainerSerde")
+            .field("_marker", &self._marker)
+            .field("container", &&self.container)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, C: Clone> Clone for ContainerSerde<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.container.clone())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.container.clone_from(&source.container);
+    }
+}
+
+/// Serializes the inner `T` of the container, borrowed with [`get_ref`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// If the container is [fragile], serialization may panic or deadlock; see the
+/// [type-level documentation](ContainerSerde).
+///
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T, C> Serialize for ContainerSerde<T, C>
+where
+    T: ?Sized + Serialize,
+    C: ?Sized + FragileContainer<T>,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (*self.container.get_ref()).serialize(serializer)
+    }
+}
+
+/// Deserializes a `T`, and creates a new container around it with [`new_container`].
+///
+/// [`new_container`]: ContainerNew::new_container
+impl<'de, T, C> Deserialize<'de> for ContainerSerde<T, C>
+where
+    T: Deserialize<'de>,
+    C: ContainerNew<T>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(|t| Self::new(C::new_container(t)))
+    }
+}
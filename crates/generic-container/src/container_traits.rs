@@ -1,6 +1,44 @@
+use core::convert::Infallible;
+use core::error::Error;
+use core::mem;
 use core::ops::{Deref, DerefMut};
+#[cfg(any(feature = "alloc", doc))]
+use alloc::boxed::Box;
 
 
+// ================================================================
+//  The error bound
+// ================================================================
+
+/// A marker trait for the [`RefError`] and [`RefMutError`] types of containers.
+///
+/// Every container error is a [`core::error::Error`], so generic code can propagate it with `?`
+/// into a `Box<dyn Error>` without further bounds. Implemented for every type implementing
+/// [`Error`].
+///
+/// ## Examples
+/// ```
+/// use std::{cell::RefCell, error::Error, rc::Rc};
+/// use generic_container::{CheckedRcRefCell, FragileTryContainer};
+///
+/// fn first<C: FragileTryContainer<Vec<u8>>>(container: &C) -> Result<u8, Box<dyn Error + '_>> {
+///     Ok(container.try_get_ref()?.first().copied().ok_or("empty")?)
+/// }
+///
+/// let container = CheckedRcRefCell(Rc::new(RefCell::new(vec![3])));
+/// assert_eq!(first(&container).unwrap(), 3);
+///
+/// let borrow = container.0.borrow_mut();
+/// assert!(first(&container).is_err());
+/// drop(borrow);
+/// ```
+///
+/// [`RefError`]: FragileTryContainer::RefError
+/// [`RefMutError`]: FragileTryMutContainer::RefMutError
+pub trait ContainerError: Error {}
+
+impl<E: ?Sized + Error> ContainerError for E {}
+
 // ================================================================
 //  The four `{Fragile|}{Try|}Container` traits
 // ================================================================
@@ -30,22 +68,17 @@ pub trait FragileTryContainer<T: ?Sized> {
     /// May have a nontrivial `Drop` implementatation, as with the [`Ref`] type corresponding
     /// to [`RefCell`].
     ///
-    /// [`Ref`]: std::cell::Ref
-    /// [`RefCell`]: std::cell::RefCell
+    /// [`Ref`]: core::cell::Ref
+    /// [`RefCell`]: core::cell::RefCell
     type Ref<'a>:  Deref<Target = T> where Self: 'a;
-    /// An error that might be returned by [`try_get_ref`]. This type should implement
-    /// [`std::error::Error`].
+    /// An error that might be returned by [`try_get_ref`].
     ///
     /// The canonical error to use when [`try_get_ref`] can never return an error
     /// is [`Infallible`].
     ///
     /// [`try_get_ref`]: FragileTryContainer::try_get_ref
-    /// [`Infallible`]: std::convert::Infallible
-    type RefError;
-
-    /// Create a new container that owns the provided `T`.
-    #[must_use]
-    fn new_container(t: T) -> Self where Self: Sized, T: Sized;
+    /// [`Infallible`]: core::convert::Infallible
+    type RefError: ContainerError;
 
     /// Attempt to retrieve the inner `T` from the container.
     ///
@@ -56,7 +89,9 @@ pub trait FragileTryContainer<T: ?Sized> {
     /// `into_inner` is called on each of those containers, then an implementation should return
     /// `Some(T)` for exactly one of them, unless there is some useful reason for the implementation
     /// to do otherwise.
-    #[must_use]
+    ///
+    /// A container whose `into_inner` never returns `None` should implement [`StrictContainer`].
+    #[must_use = "the contained value is dropped if the result is discarded"]
     fn into_inner(self) -> Option<T> where Self: Sized, T: Sized;
 
     /// Attempt to immutably access the inner `T`.
@@ -77,7 +112,7 @@ pub trait FragileTryContainer<T: ?Sized> {
     /// permitted to panic or deadlock if this method is called from a thread which already has a
     /// reference to the inner `T` of this container.
     ///
-    /// [Read more about fragility.](crate#fragility-potential-panics-or-deadlocks)
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
     ///
     /// # Errors
     ///
@@ -112,7 +147,7 @@ pub trait FragileContainer<T: ?Sized>: FragileTryContainer<T> {
     /// permitted to panic or deadlock if this method is called from a thread which already has a
     /// reference to the inner `T` of this container.
     ///
-    /// [Read more about fragility.](crate#fragility-potential-panics-or-deadlocks)
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
     #[must_use]
     fn get_ref(&self) -> Self::Ref<'_>;
 }
@@ -175,18 +210,17 @@ pub trait FragileTryMutContainer<T: ?Sized>: FragileTryContainer<T> {
     /// May have a nontrivial `Drop` implementatation, as with the [`RefMut`] type corresponding
     /// to [`RefCell`].
     ///
-    /// [`RefMut`]: std::cell::RefMut
-    /// [`RefCell`]: std::cell::RefCell
+    /// [`RefMut`]: core::cell::RefMut
+    /// [`RefCell`]: core::cell::RefCell
     type RefMut<'a>:  DerefMut<Target = T> where Self: 'a;
-    /// An error that might be returned by [`try_get_mut`]. This type should implement
-    /// [`std::error::Error`].
+    /// An error that might be returned by [`try_get_mut`].
     ///
     /// The canonical error to use when [`try_get_mut`] can never return an error
     /// is [`Infallible`].
     ///
     /// [`try_get_mut`]: FragileTryMutContainer::try_get_mut
-    /// [`Infallible`]: std::convert::Infallible
-    type RefMutError;
+    /// [`Infallible`]: core::convert::Infallible
+    type RefMutError: ContainerError;
 
     /// Attempt to mutably access the inner `T`.
     ///
@@ -206,11 +240,48 @@ pub trait FragileTryMutContainer<T: ?Sized>: FragileTryContainer<T> {
     /// permitted to panic or deadlock if this method is called from a thread which already has a
     /// reference to the inner `T` of this container.
     ///
-    /// [Read more about fragility.](crate#fragility-potential-panics-or-deadlocks)
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
     ///
     /// # Errors
     /// Errors are implementation-defined, and should be documented by implementors.
     fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError>;
+
+    /// Attempt to mutably borrow the inner `T` with [`try_get_mut`], and return the result of
+    /// calling `f` on it.
+    ///
+    /// The borrow is held for the entirety of `f`, so the inner `T` can be read, checked, and
+    /// modified in a single step.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// The container is borrowed while `f` runs. Unless this container is a [`TryMutContainer`],
+    /// `f` must not borrow the same container.
+    ///
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// # Errors
+    /// Errors if and only if [`try_get_mut`] errors, in which case `f` is not called.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::{CheckedRcRefCell, ContainerNew as _, FragileTryMutContainer as _};
+    ///
+    /// let mut counter = CheckedRcRefCell::new_container(0_u32);
+    /// let previous = counter.try_update(|count: &mut u32| {
+    ///     let previous = *count;
+    ///     *count += 1;
+    ///     previous
+    /// });
+    ///
+    /// assert_eq!(previous.unwrap(), 0);
+    /// assert_eq!(*counter.0.borrow(), 1);
+    /// ```
+    ///
+    /// [`try_get_mut`]: FragileTryMutContainer::try_get_mut
+    #[inline]
+    fn try_update<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Result<R, Self::RefMutError> {
+        self.try_get_mut().map(|mut inner| f(&mut inner))
+    }
 }
 
 /// An abstraction over some container which owns a `T` and can infallibly provide mutable or
@@ -243,9 +314,85 @@ pub trait FragileMutContainer<T: ?Sized>: FragileTryMutContainer<T> + FragileCon
     /// permitted to panic or deadlock if this method is called from a thread which already has a
     /// reference to the inner `T` of this container.
     ///
-    /// [Read more about fragility.](crate#fragility-potential-panics-or-deadlocks)
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
     #[must_use]
     fn get_mut(&mut self) -> Self::RefMut<'_>;
+
+    /// Mutably borrow the inner `T` with [`get_mut`], and return the result of calling `f` on it.
+    ///
+    /// The borrow is held for the entirety of `f`, so the inner `T` can be read, checked, and
+    /// modified in a single step.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// The container is borrowed while `f` runs. Unless this container is a [`MutContainer`],
+    /// `f` must not borrow the same container.
+    ///
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use generic_container::FragileMutContainer as _;
+    ///
+    /// let mut counter = Arc::new(Mutex::new(0_u32));
+    /// let incremented = counter.update(|count: &mut u32| {
+    ///     if *count < 10 {
+    ///         *count += 1;
+    ///         true
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    ///
+    /// assert!(incremented);
+    /// assert_eq!(*counter.lock().unwrap(), 1);
+    /// ```
+    ///
+    /// [`get_mut`]: FragileMutContainer::get_mut
+    #[inline]
+    fn update<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> R {
+        f(&mut self.get_mut())
+    }
+
+    /// Replace the inner `T` with its [`Default`] value, and return the previous value.
+    ///
+    /// The value is swapped out through a single [`get_mut`] borrow, without cloning it or
+    /// consuming the container. For a shared container such as `Arc<Mutex<T>>` or
+    /// `Arc<RwLock<T>>`, the swap happens under the lock, so every clone of the container
+    /// observes either the old value or the default value, and poison is handled as in
+    /// [`get_mut`].
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// Unless this container is a [`MutContainer`], this must not be called from a thread which
+    /// already has a reference to the inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use generic_container::FragileMutContainer as _;
+    ///
+    /// let mut buffer = Arc::new(Mutex::new(Vec::new()));
+    /// let writer = Arc::clone(&buffer);
+    /// writer.lock().unwrap().extend_from_slice(b"flushed");
+    ///
+    /// let flushed: Vec<u8> = buffer.take();
+    /// assert_eq!(flushed, b"flushed");
+    /// assert!(writer.lock().unwrap().is_empty());
+    /// ```
+    ///
+    /// [`get_mut`]: FragileMutContainer::get_mut
+    #[inline]
+    #[must_use = "the previous value is dropped if the result is discarded"]
+    fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        mem::take(&mut *self.get_mut())
+    }
 }
 
 /// An abstraction over some container which owns a `T` and can provide mutable or immutable
@@ -279,6 +426,367 @@ pub trait TryMutContainer<T: ?Sized>: FragileTryMutContainer<T> + TryContainer<T
 /// [`get_mut`]: FragileMutContainer::get_mut
 pub trait MutContainer<T: ?Sized>: FragileMutContainer<T> + TryMutContainer<T> + Container<T> {}
 
+// ================================================================
+//  The constructor trait
+// ================================================================
+
+/// A container which can be created from the `T` that it should contain.
+///
+/// Creating a container is kept separate from [`FragileTryContainer`], so that containers which
+/// cannot be constructed from a `T`, such as `&T` and `&mut T`, can still implement the container
+/// traits. Generic code that creates containers should add a `ContainerNew<T>` bound alongside
+/// whichever container trait it needs.
+///
+/// ## Examples
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use generic_container::{ContainerNew, FragileMutContainer};
+///
+/// fn counter<C: ContainerNew<u32> + FragileMutContainer<u32>>() -> C {
+///     let mut container = C::new_container(0);
+///     *container.get_mut() += 1;
+///     container
+/// }
+///
+/// let boxed: Box<u32> = counter();
+/// let shared: Rc<RefCell<u32>> = counter();
+/// assert_eq!(*boxed, 1);
+/// assert_eq!(*shared.borrow(), 1);
+/// ```
+pub trait ContainerNew<T: ?Sized>: FragileTryContainer<T> {
+    /// Create a new container that owns the provided `T`.
+    #[must_use]
+    fn new_container(t: T) -> Self where Self: Sized, T: Sized;
+}
+
+/// A container which can attempt to be created from the `T` that it should contain, where creation
+/// may fail.
+///
+/// This is intended for containers with fallible construction, such as those drawn from a bounded
+/// pool or which allocate fallibly. Every [`ContainerNew<T>`] container implements this trait with
+/// an [`Infallible`] error, so generic code may use a `TryContainerNew<T>` bound to accept both
+/// kinds of containers.
+///
+/// ## Examples
+/// ```
+/// use generic_container::TryContainerNew;
+///
+/// let boxed = <Box<u32> as TryContainerNew<u32>>::try_new_container(5);
+/// assert!(boxed.is_ok_and(|boxed| *boxed == 5));
+/// ```
+///
+/// [`ContainerNew<T>`]: ContainerNew
+pub trait TryContainerNew<T: ?Sized>: FragileTryContainer<T> {
+    /// The error returned if a container could not be created.
+    type NewError;
+
+    /// Attempt to create a new container that owns the provided `T`.
+    ///
+    /// # Errors
+    ///
+    /// Errors are implementation-defined, and should be documented by implementors.
+    fn try_new_container(t: T) -> Result<Self, Self::NewError> where Self: Sized, T: Sized;
+}
+
+impl<T: ?Sized, C: ?Sized + ContainerNew<T>> TryContainerNew<T> for C {
+    type NewError = Infallible;
+
+    /// Infallibly create a new container with [`ContainerNew::new_container`].
+    #[inline]
+    fn try_new_container(t: T) -> Result<Self, Self::NewError> where Self: Sized, T: Sized {
+        Ok(Self::new_container(t))
+    }
+}
+
+/// A container which can be created from a boxed `T`, even if `T` is unsized.
+///
+/// [`ContainerNew::new_container`] requires `T: Sized`, and unsizing a container (for instance,
+/// from `Rc<[u32; 3]>` to `Rc<[u32]>`, or from `Box<{closure}>` to `Box<dyn Fn()>`) generically
+/// requires nightly-only traits. Instead, a `Box<T>` can be created by unsizing coercion, and then
+/// converted into the container, without copying the `T` in the case of `Box<T>` itself. A
+/// function argument is a coercion site, so `C::from_boxed(Box::new(value))` usually coerces the
+/// box to `Box<T>` automatically.
+///
+/// Containers which store their `T` inside another type, such as `Arc<Mutex<T>>`, cannot be
+/// created from an unsized `Box<T>` on stable Rust, and do not implement this trait.
+///
+/// ## Examples
+/// ```
+/// use std::{fmt::Display, rc::Rc};
+/// use generic_container::ContainerFromBox;
+///
+/// fn describe<C: ContainerFromBox<dyn Display>>(value: u32) -> C {
+///     C::from_boxed(Box::new(value))
+/// }
+///
+/// let shared: Rc<dyn Display> = describe(5);
+/// assert_eq!(shared.to_string(), "5");
+/// ```
+#[cfg(any(feature = "alloc", doc))]
+pub trait ContainerFromBox<T: ?Sized>: FragileTryContainer<T> {
+    /// Create a new container that owns the provided boxed `T`.
+    #[must_use]
+    fn from_boxed(boxed: Box<T>) -> Self where Self: Sized;
+}
+
+/// An extension trait for creating a container around `T::default()`.
+///
+/// Implemented for every [`ContainerNew<T>`] container, so generic code can write
+/// `C::default_container()` instead of `C::new_container(T::default())`.
+///
+/// ## Examples
+/// ```
+/// use generic_container::DefaultContainer;
+/// # #[cfg(feature = "kinds")] {
+/// use generic_container::kinds::{ArcKind, ArcLike};
+///
+/// fn empty_list<K: ArcLike>() -> K::Container<Vec<u32>> {
+///     K::Container::<Vec<u32>>::default_container()
+/// }
+///
+/// let list = empty_list::<ArcKind>();
+/// assert!(list.is_empty());
+/// # }
+/// ```
+///
+/// [`ContainerNew<T>`]: ContainerNew
+pub trait DefaultContainer<T: ?Sized>: ContainerNew<T> {
+    /// Create a new container that owns `T::default()`.
+    #[must_use]
+    fn default_container() -> Self where Self: Sized, T: Default + Sized;
+}
+
+impl<T: ?Sized, C: ?Sized + ContainerNew<T>> DefaultContainer<T> for C {
+    #[inline]
+    fn default_container() -> Self where Self: Sized, T: Default + Sized {
+        Self::new_container(T::default())
+    }
+}
+
+/// An extension trait for getting a raw pointer to the `T` inside a container, without borrowing
+/// the container.
+///
+/// This is useful for debugging aliasing, and enables the [`Pointer`] implementation of
+/// [`GenericContainer`] (so `{:p}` can be used). For shared containers like `Arc<T>` or
+/// `Rc<RefCell<T>>`, every clone of a container points to the same `T`.
+///
+/// The returned pointer is only valid while the container is alive (and, for containers which
+/// own their `T` directly, while the container is not moved). Dereferencing it is subject to the
+/// usual aliasing rules; in particular, for containers with interior mutability, the `T` might be
+/// mutably borrowed elsewhere.
+///
+/// Note that `Arc<Mutex<T>>` and `Arc<RwLock<T>>` do not implement this trait, as the standard
+/// library does not yet provide stable access to a lock's data pointer without locking it.
+///
+/// ## Examples
+/// ```
+/// use std::rc::Rc;
+/// use generic_container::ContainerPointer;
+///
+/// let shared = Rc::new(5_u8);
+/// let clone = Rc::clone(&shared);
+/// assert_eq!(ContainerPointer::<u8>::as_ptr(&shared), ContainerPointer::<u8>::as_ptr(&clone));
+/// ```
+///
+/// [`Pointer`]: core::fmt::Pointer
+/// [`GenericContainer`]: crate::GenericContainer
+pub trait ContainerPointer<T: ?Sized>: FragileTryContainer<T> {
+    /// Get a raw pointer to the `T` inside this container, without borrowing the container.
+    #[must_use]
+    fn as_ptr(&self) -> *const T;
+}
+
+/// A shared container which can be downgraded to a weak handle, which does not keep the `T`
+/// alive and can later be upgraded back into the container.
+///
+/// Weak handles are intended for back-references (for instance, from a child node to its
+/// parent), which would otherwise form a reference cycle and leak.
+///
+/// ## Examples
+/// ```
+/// use std::rc::Rc;
+/// use generic_container::WeakContainer;
+///
+/// let strong = Rc::new(5_u8);
+/// let weak = WeakContainer::<u8>::downgrade(&strong);
+/// assert_eq!(<Rc<u8> as WeakContainer<u8>>::upgrade(&weak).as_deref(), Some(&5));
+///
+/// drop(strong);
+/// assert!(<Rc<u8> as WeakContainer<u8>>::upgrade(&weak).is_none());
+/// ```
+pub trait WeakContainer<T: ?Sized>: FragileTryContainer<T> + Sized {
+    /// A weak handle to the container's inner `T`, such as [`alloc::rc::Weak`] for `Rc<T>`.
+    type Weak;
+
+    /// Create a weak handle to the inner `T` of this container.
+    #[must_use]
+    fn downgrade(&self) -> Self::Weak;
+
+    /// Attempt to recover a strong container from a weak handle.
+    ///
+    /// Returns `None` if every strong container sharing the inner `T` has already been dropped.
+    #[must_use]
+    fn upgrade(weak: &Self::Weak) -> Option<Self>;
+}
+
+/// A container whose immutable borrows can be projected into a component of the inner `T`, such
+/// as one of its fields.
+///
+/// A [`Ref`] can be projected in different ways depending on the container: a plain reference can
+/// simply be mapped, a [`RefCell`]'s borrow can be mapped with [`Ref::map`], and a lock guard
+/// must be kept alive alongside the projected reference, as in [`MappedGuard`]. This trait
+/// abstracts over those approaches.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// [`map_ref`] borrows the container with [`get_ref`], and the returned value holds that borrow.
+/// If the container is [fragile], the same restrictions as for [`get_ref`] apply.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::ProjectableContainer;
+///
+/// struct Person {
+///     name: String,
+///     age:  u8,
+/// }
+///
+/// fn name<C: ProjectableContainer<Person>>(person: &C) -> C::MappedRef<'_, str> {
+///     person.map_ref(|person| person.name.as_str())
+/// }
+///
+/// let boxed = Box::new(Person { name: "Ada".to_owned(), age: 36 });
+/// let shared = Arc::new(Mutex::new(Person { name: "Grace".to_owned(), age: 45 }));
+///
+/// assert_eq!(&*name(&boxed), "Ada");
+/// assert_eq!(&*name(&shared), "Grace");
+/// ```
+///
+/// [`Ref`]: FragileTryContainer::Ref
+/// [`RefCell`]: core::cell::RefCell
+/// [`Ref::map`]: core::cell::Ref::map
+/// [`MappedGuard`]: crate::MappedGuard
+/// [`map_ref`]: ProjectableContainer::map_ref
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait ProjectableContainer<T: ?Sized>: FragileContainer<T> {
+    /// An immutably borrowed component `U` of the inner `T`.
+    type MappedRef<'a, U: ?Sized + 'a>: Deref<Target = U> where Self: 'a;
+
+    /// Immutably borrow the inner `T` with [`get_ref`], and project the borrow with `f`.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// Unless this container is a [`Container`], implementations are permitted to panic or
+    /// deadlock if this method is called from a thread which already has a reference to the
+    /// inner `T` of this container.
+    ///
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    #[must_use]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U;
+}
+
+/// A container whose [`into_inner`] never returns `None`, such as `Box<T>` or `T` itself.
+///
+/// Containers which may share their inner `T`, like `Rc<T>` or `Arc<T>`, can only return the
+/// inner `T` if no other reference to it exists, so they do not implement this trait. Code which
+/// only works with singly-owned containers can bound by `StrictContainer<T>` and use
+/// [`into_value`] without handling `None`.
+///
+/// ## Examples
+/// ```
+/// use std::sync::Mutex;
+/// use generic_container::StrictContainer;
+///
+/// fn take<C: StrictContainer<Vec<u8>>>(container: C) -> Vec<u8> {
+///     container.into_value()
+/// }
+///
+/// assert_eq!(take(Box::new(vec![1])), [1]);
+/// assert_eq!(take(Mutex::new(vec![2])), [2]);
+/// ```
+///
+/// [`into_inner`]: FragileTryContainer::into_inner
+/// [`into_value`]: StrictContainer::into_value
+pub trait StrictContainer<T: ?Sized>: FragileTryContainer<T> {
+    /// Infallibly retrieve the inner `T` from the container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container's [`into_inner`] implementation returns `None`, which is a bug in
+    /// the implementation of `StrictContainer`.
+    ///
+    /// [`into_inner`]: FragileTryContainer::into_inner
+    #[inline]
+    #[must_use = "the contained value is dropped if the result is discarded"]
+    fn into_value(self) -> T
+    where
+        Self: Sized,
+        T: Sized,
+    {
+        #[expect(
+            clippy::expect_used,
+            reason = "a `StrictContainer` which returns `None` from `into_inner` is a bug",
+        )]
+        self.into_inner().expect("`into_inner` of a `StrictContainer` returned `None`")
+    }
+}
+
+/// A container which can attempt to create another handle to the same inner `T` (or to a copy of
+/// it), where doing so may fail.
+///
+/// [`Clone`] cannot express failure, but some containers (such as ones drawn from a bounded pool)
+/// may not always be able to be cloned. Implemented infallibly, with an [`Infallible`] error, for
+/// every [`Clone`] container, so that code which is generic over shareable containers can bound by
+/// `TryCloneContainer<T>` without excluding such containers.
+///
+/// ## Examples
+/// ```
+/// use generic_container::TryCloneContainer;
+/// # #[cfg(feature = "kinds")] {
+/// use generic_container::kinds::{RcKind, RcLike};
+///
+/// fn share<K: RcLike>(value: u32) -> (K::Container<u32>, K::Container<u32>) {
+///     let first = K::wrap(value);
+///     let Ok(second) = first.try_clone();
+///     (first, second)
+/// }
+///
+/// let (first, second) = share::<RcKind>(1);
+/// assert!(std::rc::Rc::ptr_eq(&first, &second));
+/// # }
+/// ```
+pub trait TryCloneContainer<T: ?Sized>: FragileTryContainer<T> + Sized {
+    /// An error that might be returned by [`try_clone`].
+    ///
+    /// [`try_clone`]: TryCloneContainer::try_clone
+    type CloneError: ContainerError;
+
+    /// Attempt to clone this container.
+    ///
+    /// # Errors
+    ///
+    /// Errors are implementation-defined, and should be documented by implementors.
+    fn try_clone(&self) -> Result<Self, Self::CloneError>;
+}
+
+impl<T: ?Sized, C: FragileTryContainer<T> + Clone> TryCloneContainer<T> for C {
+    type CloneError = Infallible;
+
+    /// Infallibly clone the container.
+    #[inline]
+    fn try_clone(&self) -> Result<Self, Self::CloneError> {
+        Ok(self.clone())
+    }
+}
+
 // ================================================================
 //  The two `*Base*Container` traits intended as aliases
 // ================================================================
@@ -0,0 +1,79 @@
+use alloc::boxed::Box;
+use core::ops::Deref;
+
+use crate::container_traits::FragileContainer;
+
+
+/// An object-safe abstraction over some container which owns a `T` and can infallibly provide
+/// immutable references to it.
+///
+/// The container traits have generic associated types, so they cannot be used as trait objects.
+/// `DynContainer<T>` is an object-safe counterpart of [`FragileContainer<T>`], which allows
+/// containers of different types (for instance, created by a [container kind](crate::kinds)
+/// selected at runtime) to be stored and used uniformly as `Box<dyn DynContainer<T>>`.
+///
+/// Each borrow of the inner `T` allocates, in order to erase the type of the container's
+/// [`Ref`]; as such, this is intended for code where the container type is not known at compile
+/// time, rather than for hot paths.
+///
+/// Implemented for every [`FragileContainer<T>`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Unless the underlying container is known to be a [`Container<T>`], a `DynContainer<T>` should
+/// be treated as [fragile].
+///
+/// ## Examples
+/// ```
+/// use std::{rc::Rc, sync::{Arc, Mutex}};
+/// use generic_container::DynContainer;
+///
+/// let containers: Vec<Box<dyn DynContainer<u32>>> = vec![
+///     Rc::new(1).boxed(),
+///     Arc::new(Mutex::new(2)).boxed(),
+/// ];
+/// let mut sum = 0;
+/// for container in &containers {
+///     // `Box<dyn DynContainer<u32>>` is itself a container, so dereference it first.
+///     sum += **(**container).get_ref_dyn();
+/// }
+/// assert_eq!(sum, 3);
+/// ```
+///
+/// [`FragileContainer<T>`]: FragileContainer
+/// [`Ref`]: crate::FragileTryContainer::Ref
+/// [`Container<T>`]: crate::Container
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait DynContainer<T: ?Sized> {
+    /// Immutably borrow the inner `T`, as with [`FragileContainer::get_ref`], boxing the
+    /// borrowed value to erase its type.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    ///
+    /// Implementations are permitted to panic or deadlock if this method is called from a thread
+    /// which already has a reference to the inner `T` of this container, unless the underlying
+    /// container is a [`Container`].
+    ///
+    /// [Read more about fragility](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// [`Container`]: crate::Container
+    #[must_use]
+    fn get_ref_dyn<'a>(&'a self) -> Box<dyn Deref<Target = T> + 'a> where T: 'a;
+
+    /// Box this container as a `dyn DynContainer<T>`.
+    #[inline]
+    #[must_use]
+    fn boxed(self) -> Box<dyn DynContainer<T>>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T: ?Sized, C: ?Sized + FragileContainer<T>> DynContainer<T> for C {
+    #[inline]
+    fn get_ref_dyn<'a>(&'a self) -> Box<dyn Deref<Target = T> + 'a> where T: 'a {
+        Box::new(self.get_ref())
+    }
+}
@@ -5,13 +5,22 @@
 
 use core::{cmp::Ordering, marker::PhantomData};
 use core::{
-    fmt::{Debug, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Pointer, Result as FmtResult},
     hash::{Hash, Hasher},
 };
+#[cfg(any(feature = "alloc", doc))]
+use alloc::{boxed::Box, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(any(feature = "alloc", doc))]
+use crate::container_traits::ContainerFromBox;
+use crate::container_traits::{
+    Container, ContainerNew, ContainerPointer, FragileContainer, FragileMutContainer,
+    TryContainerNew,
+};
+
 
 // Default, Debug, Copy, Clone, PartialEq<Self>, Eq, PartialOrd<Self>, Ord, and Hash are all
 // manually implemented and defer to the container.
@@ -79,6 +88,25 @@ pub struct GenericContainer<T: ?Sized, C: ?Sized> {
 impl<T: ?Sized, C> GenericContainer<T, C> {
     /// Create a new `GenericContainer` struct wrapping the provided value, treated as a container
     /// around a specific type.
+    ///
+    /// This is a `const fn`, so a `GenericContainer` can be placed in a `static` or `const`
+    /// whenever its container can be created in a const context. That includes `T` itself,
+    /// references such as `&'static T` (see also [`from_ref`]), and, with the `std` feature,
+    /// `Mutex<T>` and `RwLock<T>`. Containers which allocate, such as `Box<T>` or `Arc<T>`,
+    /// cannot be created in const contexts.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::GenericContainer;
+    ///
+    /// static LIMIT: GenericContainer<u32, u32> = GenericContainer::new(16);
+    /// static NAMES: GenericContainer<[&str], &[&str]> = GenericContainer::from_ref(&["a", "b"]);
+    ///
+    /// assert_eq!(LIMIT.map_ref(|limit| *limit), 16);
+    /// assert_eq!(NAMES.map_ref(<[&str]>::len), 2);
+    /// ```
+    ///
+    /// [`from_ref`]: GenericContainer::from_ref
     #[inline]
     #[must_use]
     pub const fn new(container: C) -> Self {
@@ -87,6 +115,246 @@ impl<T: ?Sized, C> GenericContainer<T, C> {
             container,
         }
     }
+
+    /// Create a new `GenericContainer` whose container owns the provided `T`.
+    ///
+    /// To create a container which may fail to be constructed, see [`try_from_value`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use generic_container::GenericContainer;
+    ///
+    /// let container = GenericContainer::<u32, Rc<u32>>::from_value(5);
+    /// assert_eq!(container.map_ref(|value| *value), 5);
+    /// ```
+    ///
+    /// [`try_from_value`]: GenericContainer::try_from_value
+    #[inline]
+    #[must_use]
+    pub fn from_value(value: T) -> Self
+    where
+        T: Sized,
+        C: ContainerNew<T>,
+    {
+        Self::new(C::new_container(value))
+    }
+
+    /// Attempt to create a new `GenericContainer` whose container owns the provided `T`.
+    ///
+    /// This is the fallible counterpart of [`from_value`], for containers whose construction can
+    /// fail. On success, the created container is wrapped as in [`new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`TryContainerNew::try_new_container`]. For [`ContainerNew`]
+    /// containers, the error type is [`Infallible`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::GenericContainer;
+    ///
+    /// let container = GenericContainer::<u32, Box<u32>>::try_from_value(5).unwrap();
+    /// assert_eq!(container.map_ref(|value| *value), 5);
+    /// ```
+    ///
+    /// [`from_value`]: GenericContainer::from_value
+    /// [`new`]: GenericContainer::new
+    /// [`Infallible`]: core::convert::Infallible
+    #[inline]
+    pub fn try_from_value(value: T) -> Result<Self, C::NewError>
+    where
+        T: Sized,
+        C: TryContainerNew<T>,
+    {
+        C::try_new_container(value).map(Self::new)
+    }
+
+    /// Create a new `GenericContainer` from a boxed `T`, which may be unsized.
+    ///
+    /// See [`ContainerFromBox`] for why this is useful for containers of trait objects.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::GenericContainer;
+    ///
+    /// type Callback = GenericContainer<dyn Fn() -> u32, Box<dyn Fn() -> u32>>;
+    ///
+    /// let offset = 10;
+    /// let callbacks: Vec<Callback> = vec![
+    ///     GenericContainer::from_boxed(Box::new(|| 1)),
+    ///     GenericContainer::from_boxed(Box::new(move || offset + 2)),
+    /// ];
+    /// let results: Vec<u32> = callbacks.iter().map(|callback| callback.map_ref(|f| f())).collect();
+    /// assert_eq!(results, [1, 12]);
+    /// ```
+    #[cfg(any(feature = "alloc", doc))]
+    #[inline]
+    #[must_use]
+    pub fn from_boxed(boxed: Box<T>) -> Self
+    where
+        C: ContainerFromBox<T>,
+    {
+        Self::new(C::from_boxed(boxed))
+    }
+
+    /// Consume the `GenericContainer`, returning the wrapped container.
+    #[inline]
+    #[must_use]
+    pub fn into_inner_container(self) -> C {
+        self.container
+    }
+
+    /// Transform the wrapped container with `f`, keeping the same contained type `T`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use generic_container::{FragileTryContainer as _, GenericContainer};
+    ///
+    /// let shared: GenericContainer<u8, Arc<Mutex<u8>>> = GenericContainer::new(
+    ///     Arc::new(Mutex::new(1)),
+    /// );
+    /// let boxed: GenericContainer<u8, Box<u8>> = shared.map_container(|arc| {
+    ///     Box::new(arc.into_inner().unwrap())
+    /// });
+    /// assert_eq!(*boxed.container, 1);
+    /// ```
+    #[inline]
+    pub fn map_container<D, F: FnOnce(C) -> D>(self, f: F) -> GenericContainer<T, D> {
+        GenericContainer::new(f(self.container))
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<U, C> GenericContainer<[U], C> {
+    /// Create a new `GenericContainer` around a slice with the elements of `vec`.
+    ///
+    /// Equivalent to `GenericContainer::from_boxed(vec.into_boxed_slice())`. For instance,
+    /// `Arc<[U]>` and `Box<[U]>` can be created this way.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use generic_container::GenericContainer;
+    ///
+    /// let shared: GenericContainer<[u8], Arc<[u8]>> = GenericContainer::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(shared.map_ref(<[u8]>::len), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_vec(vec: Vec<U>) -> Self
+    where
+        C: ContainerFromBox<[U]>,
+    {
+        Self::from_boxed(vec.into_boxed_slice())
+    }
+}
+
+impl<'a, T: ?Sized> GenericContainer<T, &'a T> {
+    /// Create a new `GenericContainer` around a shared reference to a `T`.
+    ///
+    /// Equivalent to [`new`], but the contained type is inferred from the reference, which is
+    /// convenient in `static` and `const` items.
+    ///
+    /// [`new`]: GenericContainer::new
+    #[inline]
+    #[must_use]
+    pub const fn from_ref(value: &'a T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ?Sized, C: ?Sized> GenericContainer<T, C> {
+    /// Get a reference to the wrapped container.
+    #[inline]
+    #[must_use]
+    pub const fn container_ref(&self) -> &C {
+        &self.container
+    }
+
+    /// Get a mutable reference to the wrapped container.
+    #[inline]
+    #[must_use]
+    pub const fn container_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+
+    /// Borrow the inner `T` with [`get_ref`], and return the result of calling `f` on it.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    /// The container is borrowed while `f` runs. If `C` is [fragile], then `f` must not borrow
+    /// the same container.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::{cell::RefCell, rc::Rc};
+    /// use generic_container::GenericContainer;
+    ///
+    /// let mut container: GenericContainer<Vec<u8>, Rc<RefCell<Vec<u8>>>> = GenericContainer::new(
+    ///     Rc::new(RefCell::new(vec![1, 2])),
+    /// );
+    ///
+    /// container.map_mut(|list| list.push(3));
+    /// assert_eq!(container.map_ref(Vec::len), 3);
+    /// ```
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[inline]
+    pub fn map_ref<R, F: FnOnce(&T) -> R>(&self, f: F) -> R
+    where
+        C: FragileContainer<T>,
+    {
+        f(&self.container.get_ref())
+    }
+
+    /// Mutably borrow the inner `T` with [`get_mut`], and return the result of calling `f` on it.
+    ///
+    /// # Fragility: Potential Panics or Deadlocks
+    /// The container is borrowed while `f` runs. If `C` is [fragile], then `f` must not borrow
+    /// the same container.
+    ///
+    /// [`get_mut`]: FragileMutContainer::get_mut
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[inline]
+    pub fn map_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> R
+    where
+        C: FragileMutContainer<T>,
+    {
+        f(&mut self.container.get_mut())
+    }
+
+    /// Return a value whose [`Debug`] implementation formats the inner `T` of the container,
+    /// borrowed with [`get_ref`], as `GenericContainer(<value>)`.
+    ///
+    /// The [`Debug`] implementation of `GenericContainer` itself formats the container `C`, which
+    /// may hide the `T` behind lock or reference-count internals. This is only available for
+    /// non-[fragile] containers, so formatting the returned value cannot panic or deadlock due to
+    /// an existing borrow of the container.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use generic_container::GenericContainer;
+    ///
+    /// let container: GenericContainer<Vec<u8>, Arc<Vec<u8>>> = GenericContainer::new(
+    ///     Arc::new(vec![1, 2]),
+    /// );
+    /// assert_eq!(format!("{:?}", container.debug_contents()), "GenericContainer([1, 2])");
+    /// ```
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[inline]
+    #[must_use]
+    pub fn debug_contents(&self) -> impl Debug
+    where
+        T: Debug,
+        C: Container<T>,
+    {
+        DebugContents(self)
+    }
 }
 
 impl<T: ?Sized, C: Default> Default for GenericContainer<T, C> {
@@ -110,6 +378,56 @@ where
     }
 }
 
+/// Formats the inner `T` of a non-fragile container, as returned by
+/// [`GenericContainer::debug_contents`].
+struct DebugContents<'a, T: ?Sized, C: ?Sized>(&'a GenericContainer<T, C>);
+
+impl<T, C> Debug for DebugContents<'_, T, C>
+where
+    T: ?Sized + Debug,
+    C: ?Sized + Container<T>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("GenericContainer")
+            .field(&&*self.0.container.get_ref())
+            .finish()
+    }
+}
+
+/// Formats the inner `T` of the container, borrowed with [`get_ref`].
+///
+/// This is only implemented for non-[fragile] containers, so formatting a `GenericContainer`
+/// cannot panic or deadlock due to an existing borrow of the container.
+///
+/// ```
+/// use generic_container::GenericContainer;
+///
+/// let container: GenericContainer<u32, u32> = GenericContainer::new(5);
+/// assert_eq!(container.to_string(), "5");
+/// ```
+///
+/// [`get_ref`]: crate::FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+impl<T, C> Display for GenericContainer<T, C>
+where
+    T: ?Sized + Display,
+    C: ?Sized + Container<T>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&*self.container.get_ref(), f)
+    }
+}
+
+impl<T: ?Sized, C: ?Sized + ContainerPointer<T>> Pointer for GenericContainer<T, C> {
+    /// Formats the address of the inner `T`, as given by [`ContainerPointer::as_ptr`].
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Pointer::fmt(&self.container.as_ptr(), f)
+    }
+}
+
 impl<T: ?Sized, C: Copy> Copy for GenericContainer<T, C> {}
 
 impl<T: ?Sized, C: Clone> Clone for GenericContainer<T, C> {
@@ -156,3 +474,49 @@ impl<T: ?Sized, C: ?Sized + Hash> Hash for GenericContainer<T, C> {
         self.container.hash(state);
     }
 }
+
+/// Borrows the wrapped container.
+///
+/// `GenericContainer` intentionally does not implement `Deref<Target = C>`: the wrapper exists to
+/// be distinct from `C` in trait implementations, and auto-deref would make `C`'s inherent methods
+/// and trait methods callable on the wrapper, blurring which type's implementation is used.
+impl<T: ?Sized, C: ?Sized> AsRef<C> for GenericContainer<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &C {
+        &self.container
+    }
+}
+
+/// Mutably borrows the wrapped container.
+///
+/// See the [`AsRef`] implementation for why `DerefMut` is not implemented.
+impl<T: ?Sized, C: ?Sized> AsMut<C> for GenericContainer<T, C> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+}
+
+/// Wraps the container, treating it as a container around `T`. Equivalent to
+/// [`GenericContainer::new`].
+///
+/// This is a blanket implementation over every container type `C`. The contained type `T` cannot
+/// be inferred from `C` alone (a `Box<u32>` is a container for both `u32` and `Box<u32>`), so the
+/// target type usually needs to be annotated. Since this implementation covers every `C`, it
+/// replaces any per-container `From<C>` implementation for `GenericContainer<T, C>`.
+///
+/// ## Examples
+/// ```
+/// use std::sync::Arc;
+/// use generic_container::GenericContainer;
+///
+/// let boxed: GenericContainer<u32, Box<u32>> = Box::new(1).into();
+/// let shared: GenericContainer<u32, Arc<u32>> = Arc::new(2).into();
+/// assert_eq!(boxed.map_ref(|value| *value) + shared.map_ref(|value| *value), 3);
+/// ```
+impl<T: ?Sized, C> From<C> for GenericContainer<T, C> {
+    #[inline]
+    fn from(container: C) -> Self {
+        Self::new(container)
+    }
+}
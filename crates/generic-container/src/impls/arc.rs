@@ -1,18 +1,16 @@
 use core::convert::Infallible;
-use alloc::sync::Arc;
+use alloc::{boxed::Box, sync::{Arc, Weak}};
 
-use crate::container_traits::{Container, FragileContainer, FragileTryContainer, TryContainer};
+use crate::container_traits::{
+    Container, ContainerFromBox, ContainerNew, ContainerPointer, FragileContainer,
+    FragileTryContainer, ProjectableContainer, TryContainer, WeakContainer,
+};
 
 
 impl<T: ?Sized> FragileTryContainer<T> for Arc<T> {
     type Ref<'a>  = &'a T where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(t)
-    }
-
     /// Attempt to retrieve the inner `T` from the container.
     ///
     /// Uses [`Arc::into_inner`].
@@ -28,6 +26,41 @@ impl<T: ?Sized> FragileTryContainer<T> for Arc<T> {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Arc<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(t)
+    }
+}
+
+impl<T: ?Sized> ContainerFromBox<T> for Arc<T> {
+    #[inline]
+    fn from_boxed(boxed: Box<T>) -> Self {
+        Self::from(boxed)
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for Arc<T> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        Self::as_ptr(self)
+    }
+}
+
+impl<T: ?Sized> WeakContainer<T> for Arc<T> {
+    type Weak = Weak<T>;
+
+    #[inline]
+    fn downgrade(&self) -> Self::Weak {
+        Self::downgrade(self)
+    }
+
+    #[inline]
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
 impl<T: ?Sized> TryContainer<T> for Arc<T> {}
 
 impl<T: ?Sized> FragileContainer<T> for Arc<T> {
@@ -38,4 +71,18 @@ impl<T: ?Sized> FragileContainer<T> for Arc<T> {
     }
 }
 
+impl<T: ?Sized> ProjectableContainer<T> for Arc<T> {
+    type MappedRef<'a, U: ?Sized + 'a> = &'a U where T: 'a;
+
+    /// Maps the reference to the inner `T` with `f`.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        f(self)
+    }
+}
+
 impl<T: ?Sized> Container<T> for Arc<T> {}
@@ -0,0 +1,79 @@
+use core::convert::Infallible;
+use alloc::sync::Arc;
+
+use async_lock::{Mutex, MutexGuard};
+
+use crate::async_container::{AsyncContainer, AsyncContainerNew, AsyncMutContainer};
+
+
+impl<T: ?Sized + Send> AsyncContainer<T> for Arc<Mutex<T>> {
+    type Ref<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Self::into_inner(self).map(Mutex::into_inner)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn try_get_ref_async(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.lock().await)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn get_ref_async(&self) -> Self::Ref<'_> {
+        self.lock().await
+    }
+}
+
+impl<T: ?Sized + Send> AsyncContainerNew<T> for Arc<Mutex<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(Mutex::new(t))
+    }
+}
+
+impl<T: ?Sized + Send> AsyncMutContainer<T> for Arc<Mutex<T>> {
+    type RefMut<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn try_get_mut_async(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.lock().await)
+    }
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn get_mut_async(&mut self) -> Self::RefMut<'_> {
+        self.lock().await
+    }
+}
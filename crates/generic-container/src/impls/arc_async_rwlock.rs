@@ -0,0 +1,79 @@
+use core::convert::Infallible;
+use alloc::sync::Arc;
+
+use async_lock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::async_container::{AsyncContainer, AsyncContainerNew, AsyncMutContainer};
+
+
+impl<T: ?Sized + Send + Sync> AsyncContainer<T> for Arc<RwLock<T>> {
+    type Ref<'a>  = RwLockReadGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Self::into_inner(self).map(RwLock::into_inner)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn try_get_ref_async(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.read().await)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn get_ref_async(&self) -> Self::Ref<'_> {
+        self.read().await
+    }
+}
+
+impl<T: ?Sized + Send + Sync> AsyncContainerNew<T> for Arc<RwLock<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(RwLock::new(t))
+    }
+}
+
+impl<T: ?Sized + Send + Sync> AsyncMutContainer<T> for Arc<RwLock<T>> {
+    type RefMut<'a>  = RwLockWriteGuard<'a, T> where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::write`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn try_get_mut_async(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.write().await)
+    }
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::write`], so this container is
+    /// [fragile](crate::async_container#fragility-potential-deadlocks).
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    async fn get_mut_async(&mut self) -> Self::RefMut<'_> {
+        self.write().await
+    }
+}
@@ -1,22 +1,52 @@
-use alloc::sync::Arc;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use alloc::sync::{Arc, Weak};
 
 use thread_checked_lock::{
-    HandlePoisonResult as _, LockError, ThreadCheckedMutex, ThreadCheckedMutexGuard,
+    AccessError, HandlePoisonResult as _, LockError, ThreadCheckedMutex, ThreadCheckedMutexGuard,
+    TryLockError,
 };
 
+use crate::poison::MaybePoisonError;
 use crate::container_traits::{
-    FragileTryContainer, FragileTryMutContainer, TryContainer, TryMutContainer,
+    ContainerNew, FragileTryContainer, FragileTryMutContainer, TryContainer, TryMutContainer,
+    WeakContainer,
 };
 
 
 /// A version of [`thread_checked_lock::LockError`] which does not allow a poison error to be
 /// recovered into data.
+///
+/// This enum is `#[non_exhaustive]`, so that errors from other thread-checked locks can be
+/// erased into it in the future. Prefer the [`is_poison`], [`is_locked_by_current_thread`], and
+/// [`is_would_block`] accessors over matching on its variants.
+///
+/// ```
+/// use generic_container::ErasedLockError;
+/// use thread_checked_lock::ThreadCheckedMutex;
+///
+/// let mutex = ThreadCheckedMutex::new(1_u8);
+/// let guard = mutex.lock().unwrap();
+///
+/// let error = ErasedLockError::from(mutex.try_lock().err().unwrap());
+/// assert!(error.is_locked_by_current_thread());
+/// assert!(!error.panic_if_poison().is_poison());
+/// drop(guard);
+/// ```
+///
+/// [`is_poison`]: ErasedLockError::is_poison
+/// [`is_locked_by_current_thread`]: ErasedLockError::is_locked_by_current_thread
+/// [`is_would_block`]: ErasedLockError::is_would_block
 #[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub enum ErasedLockError {
     /// See [`LockError::Poisoned`]. However, the original poison error's data was already dropped.
     Poisoned,
     /// See [`LockError::LockedByCurrentThread`].
     LockedByCurrentThread,
+    /// See [`LockError::WouldBlock`]. Never returned by the container implementations for
+    /// <code>Arc<[ThreadCheckedMutex]\<T\>></code>, which block instead.
+    WouldBlock,
 }
 
 impl ErasedLockError {
@@ -28,36 +58,112 @@ impl ErasedLockError {
     /// [`Poisoned`]: ErasedLockError::Poisoned
     #[inline]
     #[must_use]
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "any variant added later should also be returned unchanged",
+    )]
+    #[expect(
+        clippy::same_name_method,
+        reason = "usable without importing `MaybePoisonError`",
+    )]
     pub fn panic_if_poison(self) -> Self {
         match self {
             #[expect(
                 clippy::panic,
                 reason = "library users will frequently want to panic on poison",
             )]
-            Self::Poisoned              => panic!("ErasedLockError was poison"),
-            Self::LockedByCurrentThread => Self::LockedByCurrentThread,
+            Self::Poisoned => panic!("ErasedLockError was poison"),
+            other          => other,
+        }
+    }
+
+    /// Determines whether the error was caused by poison.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::same_name_method,
+        reason = "usable without importing `MaybePoisonError`, and `const`",
+    )]
+    pub const fn is_poison(self) -> bool {
+        matches!(self, Self::Poisoned)
+    }
+
+    /// Determines whether the error was caused by the current thread already holding the lock.
+    #[inline]
+    #[must_use]
+    pub const fn is_locked_by_current_thread(self) -> bool {
+        matches!(self, Self::LockedByCurrentThread)
+    }
+
+    /// Determines whether the error was caused by a different thread holding the lock.
+    #[inline]
+    #[must_use]
+    pub const fn is_would_block(self) -> bool {
+        matches!(self, Self::WouldBlock)
+    }
+}
+
+impl Display for ErasedLockError {
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Poisoned => write!(
+                f,
+                "LockError due to poison (another thread panicked)",
+            ),
+            Self::LockedByCurrentThread => write!(
+                f,
+                "Failed to acquire a lock, because the same thread was holding it",
+            ),
+            Self::WouldBlock => write!(
+                f,
+                "Lock was held by a different thread, so acquiring it would block",
+            ),
         }
     }
 }
 
+impl Error for ErasedLockError {}
+
+impl MaybePoisonError for ErasedLockError {
+    #[inline]
+    fn is_poison(&self) -> bool {
+        Self::is_poison(*self)
+    }
+}
+
 impl<T> From<LockError<T>> for ErasedLockError {
     #[inline]
     fn from(value: LockError<T>) -> Self {
         match value {
             LockError::Poisoned(_)           => Self::Poisoned,
             LockError::LockedByCurrentThread => Self::LockedByCurrentThread,
+            LockError::WouldBlock            => Self::WouldBlock,
         }
     }
 }
 
-impl<T: ?Sized> FragileTryContainer<T> for Arc<ThreadCheckedMutex<T>> {
-    type Ref<'a>  = ThreadCheckedMutexGuard<'a, T> where T: 'a;
-    type RefError = ErasedLockError;
+impl<T> From<TryLockError<T>> for ErasedLockError {
+    #[inline]
+    fn from(value: TryLockError<T>) -> Self {
+        match value {
+            TryLockError::Poisoned(_)           => Self::Poisoned,
+            TryLockError::LockedByCurrentThread => Self::LockedByCurrentThread,
+            TryLockError::WouldBlock            => Self::WouldBlock,
+        }
+    }
+}
 
+impl<T> From<AccessError<T>> for ErasedLockError {
     #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(ThreadCheckedMutex::new(t))
+    fn from(_value: AccessError<T>) -> Self {
+        Self::Poisoned
     }
+}
+
+impl<T: ?Sized> FragileTryContainer<T> for Arc<ThreadCheckedMutex<T>> {
+    type Ref<'a>  = ThreadCheckedMutexGuard<'a, T> where T: 'a;
+    type RefError = ErasedLockError;
 
     /// Attempt to retrieve the inner `T` from the container.
     /// Behaves identically to [`Arc::into_inner`].
@@ -90,6 +196,68 @@ impl<T: ?Sized> FragileTryContainer<T> for Arc<ThreadCheckedMutex<T>> {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Arc<ThreadCheckedMutex<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(ThreadCheckedMutex::new(t))
+    }
+}
+
+/// The weak handle points to the same [`ThreadCheckedMutex`], so a container recovered with
+/// [`upgrade`] has the same [`id`] as the original, and the current thread's held locks are tracked
+/// across weak and strong handles.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Weak};
+/// use generic_container::{FragileTryContainer, WeakContainer};
+/// use thread_checked_lock::{LockError, ThreadCheckedMutex};
+///
+/// type Node = Arc<ThreadCheckedMutex<Links>>;
+///
+/// #[derive(Default)]
+/// struct Links {
+///     parent:   Option<Weak<ThreadCheckedMutex<Links>>>,
+///     children: Vec<Node>,
+/// }
+///
+/// let parent = Node::default();
+/// let child = Node::default();
+/// parent.lock().unwrap().children.push(Arc::clone(&child));
+/// child.lock().unwrap().parent = Some(WeakContainer::<Links>::downgrade(&parent));
+///
+/// // The upgraded handle refers to the same mutex, which is already held by this thread.
+/// let parent_guard = parent.lock().unwrap();
+/// let weak_parent = child.lock().unwrap().parent.clone().unwrap();
+/// let upgraded = <Node as WeakContainer<Links>>::upgrade(&weak_parent).unwrap();
+/// assert_eq!(upgraded.id(), parent.id());
+/// assert!(matches!(upgraded.lock(), Err(LockError::LockedByCurrentThread)));
+/// assert!(FragileTryContainer::<Links>::try_get_ref(&upgraded).is_err());
+/// drop((parent_guard, upgraded));
+///
+/// // The child's back-reference does not keep the parent alive, so the cycle does not leak.
+/// let weak_child = Arc::downgrade(&child);
+/// drop(child);
+/// drop(parent);
+/// assert!(weak_child.upgrade().is_none());
+/// ```
+///
+/// [`upgrade`]: WeakContainer::upgrade
+/// [`id`]: ThreadCheckedMutex::id
+impl<T: ?Sized> WeakContainer<T> for Arc<ThreadCheckedMutex<T>> {
+    type Weak = Weak<ThreadCheckedMutex<T>>;
+
+    #[inline]
+    fn downgrade(&self) -> Self::Weak {
+        Self::downgrade(self)
+    }
+
+    #[inline]
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
 impl<T: ?Sized> TryContainer<T> for Arc<ThreadCheckedMutex<T>> {}
 
 impl<T: ?Sized> FragileTryMutContainer<T> for Arc<ThreadCheckedMutex<T>> {
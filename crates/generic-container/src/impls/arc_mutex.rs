@@ -1,22 +1,22 @@
 use core::convert::Infallible;
-use alloc::sync::Arc;
+use alloc::sync::{Arc, Weak};
 use std::sync::{Mutex, MutexGuard};
 
 use crate::container_traits::{
-    FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, ProjectableContainer, WeakContainer,
 };
-use super::HandlePoisonedResult as _;
+use super::{HandlePoisonedResult as _, MappedGuard};
 
 
+// Note that `Arc<Mutex<T>>` deliberately implements neither `TryContainer` nor `TryMutContainer`,
+// as it is fragile. Its `try_*` methods block instead of returning errors; `CheckedArcMutex<T>`
+// is the non-fragile counterpart, which uses `Mutex::try_lock`.
+
 impl<T: ?Sized> FragileTryContainer<T> for Arc<Mutex<T>> {
     type Ref<'a>  = MutexGuard<'a, T> where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(Mutex::new(t))
-    }
-
     /// Attempt to retrieve the inner `T` from the container.
     /// Behaves identically to [`Arc::into_inner`].
     ///
@@ -38,12 +38,38 @@ impl<T: ?Sized> FragileTryContainer<T> for Arc<Mutex<T>> {
     /// already panicked.
     ///
     /// May also panic or deadlock if the contract of a fragile container is broken.
+    ///
+    /// Despite the `try_` prefix, this blocks while the mutex is held. To instead get an error
+    /// from [`Mutex::try_lock`], wrap the container in a [`CheckedArcMutex`].
+    ///
+    /// [`CheckedArcMutex`]: crate::CheckedArcMutex
     #[inline]
     fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
         Ok(self.lock().panic_if_poisoned())
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Arc<Mutex<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(Mutex::new(t))
+    }
+}
+
+impl<T: ?Sized> WeakContainer<T> for Arc<Mutex<T>> {
+    type Weak = Weak<Mutex<T>>;
+
+    #[inline]
+    fn downgrade(&self) -> Self::Weak {
+        Self::downgrade(self)
+    }
+
+    #[inline]
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
 impl<T: ?Sized> FragileContainer<T> for Arc<Mutex<T>> {
     /// Get immutable access to the inner `T`.
     ///
@@ -61,6 +87,26 @@ impl<T: ?Sized> FragileContainer<T> for Arc<Mutex<T>> {
     }
 }
 
+impl<T: ?Sized> ProjectableContainer<T> for Arc<Mutex<T>> {
+    type MappedRef<'a, U: ?Sized + 'a> = MappedGuard<MutexGuard<'a, T>, U> where T: 'a;
+
+    /// Uses [`Mutex::lock`], and keeps the guard alive alongside the projected reference.
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        MappedGuard::new(self.lock().panic_if_poisoned(), f)
+    }
+}
+
 impl<T: ?Sized> FragileTryMutContainer<T> for Arc<Mutex<T>> {
     type RefMut<'a>  = MutexGuard<'a, T> where T: 'a;
     type RefMutError = Infallible;
@@ -75,6 +121,11 @@ impl<T: ?Sized> FragileTryMutContainer<T> for Arc<Mutex<T>> {
     /// already panicked.
     ///
     /// May also panic or deadlock if the contract of a fragile container is broken.
+    ///
+    /// Despite the `try_` prefix, this blocks while the mutex is held. To instead get an error
+    /// from [`Mutex::try_lock`], wrap the container in a [`CheckedArcMutex`].
+    ///
+    /// [`CheckedArcMutex`]: crate::CheckedArcMutex
     #[inline]
     fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
         Ok(self.lock().panic_if_poisoned())
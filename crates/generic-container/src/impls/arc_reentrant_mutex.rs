@@ -0,0 +1,61 @@
+use core::convert::Infallible;
+use alloc::sync::Arc;
+
+use parking_lot::{ReentrantMutex, ReentrantMutexGuard};
+
+use crate::container_traits::{
+    Container, ContainerNew, ContainerPointer, FragileContainer, FragileTryContainer,
+    TryContainer,
+};
+
+
+impl<T: ?Sized> FragileTryContainer<T> for Arc<ReentrantMutex<T>> {
+    type Ref<'a>  = ReentrantMutexGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Self::into_inner(self).map(ReentrantMutex::into_inner)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`ReentrantMutex::lock`], which blocks while another thread holds the lock, but
+    /// which may be called recursively by the thread holding the lock.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.lock())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for Arc<ReentrantMutex<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(ReentrantMutex::new(t))
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for Arc<ReentrantMutex<T>> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.data_ptr()
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for Arc<ReentrantMutex<T>> {}
+
+impl<T: ?Sized> FragileContainer<T> for Arc<ReentrantMutex<T>> {
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`ReentrantMutex::lock`], which blocks while another thread holds the lock, but
+    /// which may be called recursively by the thread holding the lock. As such, this container
+    /// is not [fragile](crate#fragility-potential-panics-or-deadlocks).
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.lock()
+    }
+}
+
+impl<T: ?Sized> Container<T> for Arc<ReentrantMutex<T>> {}
@@ -1,22 +1,18 @@
 use core::convert::Infallible;
-use alloc::sync::Arc;
+use alloc::sync::{Arc, Weak};
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::container_traits::{
-    FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, ProjectableContainer, WeakContainer,
 };
-use super::HandlePoisonedResult as _;
+use super::{HandlePoisonedResult as _, MappedGuard};
 
 
 impl<T: ?Sized> FragileTryContainer<T> for Arc<RwLock<T>> {
     type Ref<'a>  = RwLockReadGuard<'a, T> where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(RwLock::new(t))
-    }
-
     /// Attempt to retrieve the inner `T` from the container.
     /// Behaves identically to [`Arc::into_inner`].
     ///
@@ -44,6 +40,27 @@ impl<T: ?Sized> FragileTryContainer<T> for Arc<RwLock<T>> {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Arc<RwLock<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(RwLock::new(t))
+    }
+}
+
+impl<T: ?Sized> WeakContainer<T> for Arc<RwLock<T>> {
+    type Weak = Weak<RwLock<T>>;
+
+    #[inline]
+    fn downgrade(&self) -> Self::Weak {
+        Self::downgrade(self)
+    }
+
+    #[inline]
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
 impl<T: ?Sized> FragileContainer<T> for Arc<RwLock<T>> {
     /// Get immutable access to the inner `T`.
     ///
@@ -61,6 +78,26 @@ impl<T: ?Sized> FragileContainer<T> for Arc<RwLock<T>> {
     }
 }
 
+impl<T: ?Sized> ProjectableContainer<T> for Arc<RwLock<T>> {
+    type MappedRef<'a, U: ?Sized + 'a> = MappedGuard<RwLockReadGuard<'a, T>, U> where T: 'a;
+
+    /// Uses [`RwLock::read`], and keeps the guard alive alongside the projected reference.
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        MappedGuard::new(self.read().panic_if_poisoned(), f)
+    }
+}
+
 impl<T: ?Sized> FragileTryMutContainer<T> for Arc<RwLock<T>> {
     type RefMut<'a>  = RwLockWriteGuard<'a, T> where T: 'a;
     type RefMutError = Infallible;
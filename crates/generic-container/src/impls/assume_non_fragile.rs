@@ -0,0 +1,195 @@
+#![expect(unsafe_code, reason = "constructing an `AssumeNonFragile` is an unchecked assertion")]
+
+use core::marker::PhantomData;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::container_traits::{
+    Container, FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    MutContainer, StrictContainer, TryContainer, TryMutContainer,
+};
+
+
+/// A wrapper which asserts that a [fragile] container is only used in ways that never panic or
+/// deadlock, and which therefore implements the non-fragile container traits.
+///
+/// Each of the container traits implemented by the wrapped `C` is implemented by
+/// `AssumeNonFragile<T, C>` by delegating to `C`. Additionally, [`TryContainer`] and
+/// [`Container`] are implemented if `C` implements [`FragileTryContainer`] and
+/// [`FragileContainer`], respectively, and likewise for [`TryMutContainer`] and [`MutContainer`].
+///
+/// This is the escape hatch for the fragility system: it allows a container such as
+/// `Arc<Mutex<T>>` to be passed to an API which requires a [`Container<T>`], when the caller
+/// knows that the container is never accessed reentrantly and never becomes poisoned.
+/// Prefer a non-fragile container (such as [`CheckedArcMutex`]) when that is not certain.
+///
+/// As with [`GenericContainer`], the type of the contained `T` is a parameter of the wrapper,
+/// which prevents the wrapper's trait implementations from overlapping with the blanket
+/// implementations for `T` itself.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::{AssumeNonFragile, Container};
+///
+/// fn sum<C: Container<Vec<u32>>>(container: &C) -> u32 {
+///     container.get_ref().iter().sum()
+/// }
+///
+/// // SAFETY: the mutex is only locked by `sum`, which never locks it reentrantly,
+/// // and the mutex cannot be poisoned, since nothing panics while holding its lock.
+/// let container = unsafe { AssumeNonFragile::new(Arc::new(Mutex::new(vec![1, 2, 3]))) };
+/// assert_eq!(sum(&container), 6);
+/// ```
+///
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+/// [`Container<T>`]: Container
+/// [`CheckedArcMutex`]: crate::CheckedArcMutex
+/// [`GenericContainer`]: crate::GenericContainer
+#[repr(transparent)]
+pub struct AssumeNonFragile<T: ?Sized, C: ?Sized> {
+    /// Distinguish which type is supposed to be contained.
+    _marker:   PhantomData<T>,
+    /// The container which is assumed to be non-fragile.
+    container: C,
+}
+
+impl<T: ?Sized, C> AssumeNonFragile<T, C> {
+    /// Wrap a container, asserting that it is never used in a way that would cause one of its
+    /// fragile methods to panic or deadlock.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned `AssumeNonFragile` (or any clone of it) exists, the caller
+    /// must guarantee that the wrapped container is never accessed while the current thread
+    /// already holds a reference to its inner `T`, and that none of its methods will panic or
+    /// deadlock for any other reason (for example, because a lock was poisoned).
+    ///
+    /// Breaking this contract does not, by itself, cause undefined behavior in this crate.
+    /// However, code which is generic over [`Container`] or [`MutContainer`] (including
+    /// `unsafe` code) is permitted to rely on those traits' guarantees.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new(container: C) -> Self {
+        Self {
+            _marker: PhantomData,
+            container,
+        }
+    }
+
+    /// Unwrap the container, which is then no longer assumed to be non-fragile.
+    #[inline]
+    #[must_use]
+    pub fn into_container(self) -> C {
+        self.container
+    }
+}
+
+impl<T: ?Sized, C: ?Sized> AssumeNonFragile<T, C> {
+    /// Get a reference to the wrapped container.
+    #[inline]
+    #[must_use]
+    pub const fn container(&self) -> &C {
+        &self.container
+    }
+}
+
+impl<T, C> Debug for AssumeNonFragile<T, C>
+where
+    T: ?Sized,
+    C: ?Sized + Debug,
+{
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AssumeNonFragile")
+            .field("container", &&self.container)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized, C: Copy> Copy for AssumeNonFragile<T, C> {}
+
+/// Cloning the wrapper is covered by the contract of [`AssumeNonFragile::new`], which applies to
+/// every clone.
+impl<T: ?Sized, C: Clone> Clone for AssumeNonFragile<T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            _marker:   self._marker,
+            container: self.container.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized, C: FragileTryContainer<T>> FragileTryContainer<T> for AssumeNonFragile<T, C> {
+    type Ref<'a>  = C::Ref<'a> where Self: 'a;
+    type RefError = C::RefError;
+
+    /// Delegates to the wrapped container's [`into_inner`].
+    ///
+    /// [`into_inner`]: FragileTryContainer::into_inner
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        self.container.into_inner()
+    }
+
+    /// Delegates to the wrapped container's [`try_get_ref`].
+    ///
+    /// # Errors
+    /// Returns any error returned by the wrapped container.
+    ///
+    /// [`try_get_ref`]: FragileTryContainer::try_get_ref
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        self.container.try_get_ref()
+    }
+}
+
+impl<T: ?Sized, C: StrictContainer<T>> StrictContainer<T> for AssumeNonFragile<T, C> {}
+
+impl<T: ?Sized, C: FragileTryContainer<T>> TryContainer<T> for AssumeNonFragile<T, C> {}
+
+impl<T: ?Sized, C: FragileContainer<T>> FragileContainer<T> for AssumeNonFragile<T, C> {
+    /// Delegates to the wrapped container's [`get_ref`].
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.container.get_ref()
+    }
+}
+
+impl<T: ?Sized, C: FragileContainer<T>> Container<T> for AssumeNonFragile<T, C> {}
+
+impl<T, C> FragileTryMutContainer<T> for AssumeNonFragile<T, C>
+where
+    T: ?Sized,
+    C: FragileTryMutContainer<T>,
+{
+    type RefMut<'a>  = C::RefMut<'a> where Self: 'a;
+    type RefMutError = C::RefMutError;
+
+    /// Delegates to the wrapped container's [`try_get_mut`].
+    ///
+    /// # Errors
+    /// Returns any error returned by the wrapped container.
+    ///
+    /// [`try_get_mut`]: FragileTryMutContainer::try_get_mut
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        self.container.try_get_mut()
+    }
+}
+
+impl<T: ?Sized, C: FragileTryMutContainer<T>> TryMutContainer<T> for AssumeNonFragile<T, C> {}
+
+impl<T: ?Sized, C: FragileMutContainer<T>> FragileMutContainer<T> for AssumeNonFragile<T, C> {
+    /// Delegates to the wrapped container's [`get_mut`].
+    ///
+    /// [`get_mut`]: FragileMutContainer::get_mut
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self.container.get_mut()
+    }
+}
+
+impl<T: ?Sized, C: FragileMutContainer<T>> MutContainer<T> for AssumeNonFragile<T, C> {}
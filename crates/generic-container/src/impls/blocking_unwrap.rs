@@ -0,0 +1,150 @@
+use core::hint;
+use core::time::Duration;
+use alloc::sync::Arc;
+use std::thread;
+use std::sync::{Mutex, RwLock};
+
+#[cfg(feature = "parking-lot")]
+use parking_lot::ReentrantMutex;
+#[cfg(feature = "thread-checked-lock")]
+use thread_checked_lock::{HandlePoisonResult as _, ThreadCheckedMutex};
+
+use super::{CheckedArcMutex, HandlePoisonedResult as _};
+
+
+/// Number of rounds of exponentially-increasing busy-waiting, before yielding to other threads.
+const SPIN_ROUNDS: u32 = 6;
+/// Number of rounds (including spin rounds) before sleeping, rather than yielding to other threads.
+const YIELD_ROUNDS: u32 = 16;
+/// How long to sleep between attempts once the backoff is exhausted.
+const SLEEP_DURATION: Duration = Duration::from_millis(1);
+
+
+/// Trait for taking the inner `T` out of a shared container, waiting for every other clone of
+/// the container to be dropped.
+///
+/// [`FragileTryContainer::into_inner`] returns `None` if other clones of an `Arc`-based container
+/// exist; [`into_inner_blocking`] instead blocks the current thread (with a spin-then-sleep
+/// backoff) until this is the sole strong reference, and then takes the inner `T`. This is
+/// intended for teardown paths where every other clone is known to be dropped soon.
+///
+/// Async containers are intentionally not supported, as blocking an async task on other tasks
+/// dropping their clones could stall the executor.
+///
+/// # Deadlocks
+///
+/// If some other clone of the container is never dropped, [`into_inner_blocking`] never
+/// returns. In particular, this occurs if the current thread holds another clone (or a lock
+/// guard borrowing from one), or if a clone is leaked or part of a reference cycle.
+///
+/// ## Examples
+/// ```
+/// use std::{sync::{Arc, Mutex}, thread};
+/// use generic_container::BlockingUnwrap as _;
+///
+/// let container = Arc::new(Mutex::new(vec![1, 2]));
+/// let clone = Arc::clone(&container);
+///
+/// let handle = thread::spawn(move || clone.lock().unwrap().push(3));
+///
+/// let inner: Vec<i32> = container.into_inner_blocking();
+/// assert_eq!(inner, [1, 2, 3]);
+/// # handle.join().unwrap();
+/// ```
+///
+/// [`FragileTryContainer::into_inner`]: crate::FragileTryContainer::into_inner
+/// [`into_inner_blocking`]: BlockingUnwrap::into_inner_blocking
+pub trait BlockingUnwrap<T> {
+    /// Block the current thread until this is the only clone of the container, and then return
+    /// the inner `T`.
+    ///
+    /// For lock-based containers, any poison errors are ignored.
+    ///
+    /// # Deadlocks
+    /// Never returns if some other clone of this container is never dropped.
+    /// See [`BlockingUnwrap`].
+    #[must_use]
+    fn into_inner_blocking(self) -> T;
+}
+
+/// Block the current thread until `arc` is the only strong reference to its value, and then
+/// return the value.
+fn wait_for_sole_owner<U>(mut arc: Arc<U>) -> U {
+    let mut round: u32 = 0;
+
+    loop {
+        match Arc::try_unwrap(arc) {
+            Ok(inner)   => return inner,
+            Err(shared) => arc = shared,
+        }
+
+        if round < SPIN_ROUNDS {
+            for _ in 0..(1_u32 << round) {
+                hint::spin_loop();
+            }
+        } else if round < YIELD_ROUNDS {
+            thread::yield_now();
+        } else {
+            thread::sleep(SLEEP_DURATION);
+        }
+
+        round = round.saturating_add(1);
+    }
+}
+
+impl<T> BlockingUnwrap<T> for Arc<T> {
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        wait_for_sole_owner(self)
+    }
+}
+
+impl<T> BlockingUnwrap<T> for Arc<Mutex<T>> {
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        wait_for_sole_owner(self).into_inner().ignore_poisoned()
+    }
+}
+
+impl<T> BlockingUnwrap<T> for Arc<RwLock<T>> {
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        wait_for_sole_owner(self).into_inner().ignore_poisoned()
+    }
+}
+
+impl<T> BlockingUnwrap<T> for CheckedArcMutex<T> {
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        self.0.into_inner_blocking()
+    }
+}
+
+#[cfg(feature = "thread-checked-lock")]
+impl<T> BlockingUnwrap<T> for Arc<ThreadCheckedMutex<T>> {
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        let result = wait_for_sole_owner(self)
+            .into_inner()
+            .ignore_poison();
+
+        // The result could only possibly be due to poison, so its `Err` is now uninhabited
+        match result {
+            Ok(t) => t,
+            #[expect(unreachable_code, reason = "yeah, that's the point")]
+            Err(poisonless_poison) => match poisonless_poison.poison.into_inner() {},
+        }
+    }
+}
+
+#[cfg(feature = "parking-lot")]
+impl<T> BlockingUnwrap<T> for Arc<ReentrantMutex<T>> {
+    #[inline]
+    fn into_inner_blocking(self) -> T {
+        wait_for_sole_owner(self).into_inner()
+    }
+}
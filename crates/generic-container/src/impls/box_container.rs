@@ -2,8 +2,9 @@ use core::convert::Infallible;
 use alloc::boxed::Box;
 
 use crate::container_traits::{
-    Container, FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
-    MutContainer, TryContainer, TryMutContainer,
+    Container, ContainerFromBox, ContainerNew, ContainerPointer, FragileContainer,
+    FragileMutContainer, FragileTryContainer, FragileTryMutContainer, MutContainer,
+    ProjectableContainer, StrictContainer, TryContainer, TryMutContainer,
 };
 
 
@@ -11,11 +12,6 @@ impl<T: ?Sized> FragileTryContainer<T> for Box<T> {
     type Ref<'a>  = &'a T where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(t)
-    }
-
     /// Infallibly get the inner `T` of this box.
     #[inline]
     fn into_inner(self) -> Option<T> where T: Sized {
@@ -29,6 +25,29 @@ impl<T: ?Sized> FragileTryContainer<T> for Box<T> {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Box<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(t)
+    }
+}
+
+impl<T: ?Sized> StrictContainer<T> for Box<T> {}
+
+impl<T: ?Sized> ContainerFromBox<T> for Box<T> {
+    #[inline]
+    fn from_boxed(boxed: Self) -> Self {
+        boxed
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for Box<T> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        &raw const **self
+    }
+}
+
 impl<T: ?Sized> TryContainer<T> for Box<T> {}
 
 impl<T: ?Sized> FragileContainer<T> for Box<T> {
@@ -39,6 +58,20 @@ impl<T: ?Sized> FragileContainer<T> for Box<T> {
     }
 }
 
+impl<T: ?Sized> ProjectableContainer<T> for Box<T> {
+    type MappedRef<'a, U: ?Sized + 'a> = &'a U where T: 'a;
+
+    /// Maps the reference to the inner `T` with `f`.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        f(self)
+    }
+}
+
 impl<T: ?Sized> Container<T> for Box<T> {}
 
 impl<T: ?Sized> FragileTryMutContainer<T> for Box<T> {
@@ -0,0 +1,213 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use alloc::sync::Arc;
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+use crate::poison::MaybePoisonError;
+use crate::container_traits::{
+    ContainerNew, FragileTryContainer, FragileTryMutContainer, TryContainer, TryMutContainer,
+};
+use super::HandlePoisonedResult as _;
+
+
+/// A thin wrapper around `Arc<Mutex<T>>` which implements the container traits differently:
+/// `CheckedArcMutex<T>` is fallible, but not [fragile].
+///
+/// Note that `Arc<Mutex<T>>` is an infallible but [fragile] container, which uses the blocking
+/// [`Mutex::lock`] and panics on poison. `CheckedArcMutex<T>` instead uses [`Mutex::try_lock`],
+/// and returns an error if the mutex is poisoned or is already locked (by any thread, including
+/// the current thread).
+///
+/// Unlike <code>Arc<[ThreadCheckedMutex]\<T\>></code>, this container never blocks, and cannot
+/// distinguish whether the mutex is held by the current thread or a different thread.
+///
+/// An existing `Arc<Mutex<T>>` can be converted to and from a `CheckedArcMutex<T>` with [`From`],
+/// without affecting other clones of the `Arc`.
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::{CheckedArcMutex, ErasedTryLockError, FragileTryContainer};
+///
+/// let fragile = Arc::new(Mutex::new(1_u8));
+/// let checked = CheckedArcMutex::from(Arc::clone(&fragile));
+///
+/// let guard = fragile.lock().unwrap();
+/// assert_eq!(
+///     FragileTryContainer::<u8>::try_get_ref(&checked).err(),
+///     Some(ErasedTryLockError::WouldBlock),
+/// );
+/// drop(guard);
+/// assert_eq!(*FragileTryContainer::<u8>::try_get_ref(&checked).unwrap(), 1);
+/// ```
+///
+#[cfg_attr(
+    feature = "thread-checked-lock",
+    doc = "[ThreadCheckedMutex]: thread_checked_lock::ThreadCheckedMutex",
+)]
+#[cfg_attr(
+    not(feature = "thread-checked-lock"),
+    doc = "[ThreadCheckedMutex]: \
+    https://docs.rs/thread-checked-lock/0/thread_checked_lock/struct.ThreadCheckedMutex.html",
+)]
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+#[derive(Default, Debug)]
+pub struct CheckedArcMutex<T: ?Sized>(pub Arc<Mutex<T>>);
+
+impl<T: ?Sized> Clone for CheckedArcMutex<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<T: ?Sized> From<Arc<Mutex<T>>> for CheckedArcMutex<T> {
+    #[inline]
+    fn from(container: Arc<Mutex<T>>) -> Self {
+        Self(container)
+    }
+}
+
+impl<T: ?Sized> From<CheckedArcMutex<T>> for Arc<Mutex<T>> {
+    #[inline]
+    fn from(container: CheckedArcMutex<T>) -> Self {
+        container.0
+    }
+}
+
+/// A version of [`std::sync::TryLockError`] which does not allow a poison error to be recovered
+/// into data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErasedTryLockError {
+    /// See [`TryLockError::Poisoned`]. However, the original poison error's data was already
+    /// dropped.
+    Poisoned,
+    /// See [`TryLockError::WouldBlock`]. Note that the lock might be held by the current thread.
+    WouldBlock,
+}
+
+impl ErasedTryLockError {
+    /// Panics if the error was caused by poison, and otherwise returns the error unchanged.
+    ///
+    /// # Panics
+    /// Panics if the error is the [`Poisoned`] variant.
+    ///
+    /// [`Poisoned`]: ErasedTryLockError::Poisoned
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::same_name_method,
+        reason = "usable without importing `MaybePoisonError`",
+    )]
+    pub fn panic_if_poison(self) -> Self {
+        match self {
+            #[expect(
+                clippy::panic,
+                reason = "library users will frequently want to panic on poison",
+            )]
+            Self::Poisoned   => panic!("ErasedTryLockError was poison"),
+            Self::WouldBlock => Self::WouldBlock,
+        }
+    }
+}
+
+impl Display for ErasedTryLockError {
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Poisoned => write!(
+                f,
+                "TryLockError due to poison (another thread panicked)",
+            ),
+            Self::WouldBlock => write!(
+                f,
+                "Lock was already held, so acquiring it would block",
+            ),
+        }
+    }
+}
+
+impl Error for ErasedTryLockError {}
+
+impl MaybePoisonError for ErasedTryLockError {
+    #[inline]
+    fn is_poison(&self) -> bool {
+        matches!(self, Self::Poisoned)
+    }
+}
+
+impl<T> From<TryLockError<T>> for ErasedTryLockError {
+    #[inline]
+    fn from(value: TryLockError<T>) -> Self {
+        match value {
+            TryLockError::Poisoned(_) => Self::Poisoned,
+            TryLockError::WouldBlock  => Self::WouldBlock,
+        }
+    }
+}
+
+impl<T: ?Sized> FragileTryContainer<T> for CheckedArcMutex<T> {
+    type Ref<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefError = ErasedTryLockError;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Arc::into_inner(self.0)
+            .map(Mutex::into_inner)
+            .map(Result::ignore_poisoned)
+    }
+
+    /// Attempt to immutably access the inner `T`, without blocking.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if and only if [`Mutex::try_lock`] fails. In particular, a
+    /// [`WouldBlock`] error is returned if the mutex is already locked by any thread.
+    ///
+    /// A poison error is not ignored, nor does it trigger a panic.
+    ///
+    /// [`WouldBlock`]: ErasedTryLockError::WouldBlock
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        self.0.try_lock().map_err(Into::into)
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for CheckedArcMutex<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Arc::new(Mutex::new(t)))
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for CheckedArcMutex<T> {}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for CheckedArcMutex<T> {
+    type RefMut<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefMutError = ErasedTryLockError;
+
+    /// Attempt to mutably access the inner `T`, without blocking.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if and only if [`Mutex::try_lock`] fails. In particular, a
+    /// [`WouldBlock`] error is returned if the mutex is already locked by any thread.
+    ///
+    /// A poison error is not ignored, nor does it trigger a panic.
+    ///
+    /// [`WouldBlock`]: ErasedTryLockError::WouldBlock
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        self.0.try_lock().map_err(Into::into)
+    }
+}
+
+impl<T: ?Sized> TryMutContainer<T> for CheckedArcMutex<T> {}
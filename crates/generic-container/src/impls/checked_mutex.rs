@@ -0,0 +1,69 @@
+use thread_checked_lock::{ThreadCheckedMutex, ThreadCheckedMutexGuard};
+
+use crate::container_traits::{
+    ContainerNew, FragileTryContainer, FragileTryMutContainer, StrictContainer, TryContainer,
+    TryMutContainer,
+};
+use super::ErasedLockError;
+
+
+// A `ThreadCheckedMutex<T>` is not `Clone`, so it acts like a `Box<T>` rather than an
+// `Arc<ThreadCheckedMutex<T>>`. Mutable access does not need to lock the mutex, as `&mut self`
+// already guarantees exclusive access.
+
+impl<T: ?Sized> FragileTryContainer<T> for ThreadCheckedMutex<T> {
+    type Ref<'a>  = ThreadCheckedMutexGuard<'a, T> where T: 'a;
+    type RefError = ErasedLockError;
+
+    /// Retrieve the inner `T` from the container. Always returns `Some`.
+    /// Behaves identically to [`ThreadCheckedMutex::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Some(Self::into_inner(self).unwrap_or_else(|error| error.poison.into_inner()))
+    }
+
+    /// Attempt to immutably access the inner `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if and only if [`ThreadCheckedMutex::lock`] fails.
+    ///
+    /// A poison error is not ignored, nor does it trigger a panic.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        self.lock().map_err(Into::into)
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for ThreadCheckedMutex<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(t)
+    }
+}
+
+impl<T: ?Sized> StrictContainer<T> for ThreadCheckedMutex<T> {}
+
+impl<T: ?Sized> TryContainer<T> for ThreadCheckedMutex<T> {}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for ThreadCheckedMutex<T> {
+    type RefMut<'a>  = &'a mut T where T: 'a;
+    type RefMutError = ErasedLockError;
+
+    /// Attempt to mutably access the inner `T`.
+    ///
+    /// Uses [`ThreadCheckedMutex::get_mut`], which does not lock the mutex.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if and only if the mutex is poisoned, in which case an
+    /// [`ErasedLockError::Poisoned`] error is returned.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Self::get_mut(self).map_err(Into::into)
+    }
+}
+
+impl<T: ?Sized> TryMutContainer<T> for ThreadCheckedMutex<T> {}
@@ -5,7 +5,8 @@ use core::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
 use serde::{Deserialize, Serialize};
 
 use crate::container_traits::{
-    FragileTryContainer, FragileTryMutContainer, TryContainer, TryMutContainer,
+    ContainerNew, ContainerPointer, FragileTryContainer, FragileTryMutContainer, TryContainer,
+    TryMutContainer,
 };
 
 
@@ -18,6 +19,22 @@ use crate::container_traits::{
 /// infallibility. Those restrictions still effectively apply here, but violating them results in
 /// errors being returned instead of being fatal.
 ///
+/// An existing `Rc<RefCell<T>>` can be converted to and from a `CheckedRcRefCell<T>` with [`From`],
+/// without affecting other clones of the `Rc`.
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use generic_container::{CheckedRcRefCell, FragileTryContainer, FragileTryMutContainer};
+///
+/// let fragile = Rc::new(RefCell::new(1_u8));
+/// let mut checked = CheckedRcRefCell::from(Rc::clone(&fragile));
+///
+/// let borrow = fragile.borrow();
+/// assert!(FragileTryMutContainer::<u8>::try_get_mut(&mut checked).is_err());
+/// drop(borrow);
+/// assert_eq!(*FragileTryContainer::<u8>::try_get_ref(&checked).unwrap(), 1);
+/// ```
+///
 /// [fragile]: crate#fragility-potential-panics-or-deadlocks
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -35,15 +52,138 @@ impl<T: ?Sized> Clone for CheckedRcRefCell<T> {
     }
 }
 
-impl<T: ?Sized> FragileTryContainer<T> for CheckedRcRefCell<T> {
-    type Ref<'a>  = Ref<'a, T> where T: 'a;
-    type RefError = BorrowError;
+impl<T> From<T> for CheckedRcRefCell<T> {
+    /// Create a new container around `t`, as in [`new_container`].
+    ///
+    /// [`new_container`]: ContainerNew::new_container
+    #[inline]
+    fn from(t: T) -> Self {
+        Self::new_container(t)
+    }
+}
 
+impl<T: ?Sized> From<Rc<RefCell<T>>> for CheckedRcRefCell<T> {
     #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self(Rc::new(RefCell::new(t)))
+    fn from(container: Rc<RefCell<T>>) -> Self {
+        Self(container)
+    }
+}
+
+impl<T: ?Sized> From<CheckedRcRefCell<T>> for Rc<RefCell<T>> {
+    #[inline]
+    fn from(container: CheckedRcRefCell<T>) -> Self {
+        container.0
+    }
+}
+
+impl<T: ?Sized> CheckedRcRefCell<T> {
+    /// Immutably borrows the inner `T`, without going through the container traits.
+    ///
+    /// Behaves identically to [`RefCell::try_borrow`], and to [`try_get_ref`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is currently mutably borrowed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::CheckedRcRefCell;
+    ///
+    /// let mut checked = CheckedRcRefCell::from(1_u8);
+    /// *checked.get_mut().unwrap() += 1;
+    ///
+    /// let borrow = checked.get().unwrap();
+    /// assert_eq!(*borrow, 2);
+    /// assert!(checked.clone_handle().get_mut().is_err());
+    /// ```
+    ///
+    /// [`try_get_ref`]: FragileTryContainer::try_get_ref
+    #[inline]
+    pub fn get(&self) -> Result<Ref<'_, T>, BorrowError> {
+        self.0.try_borrow()
+    }
+
+    /// Mutably borrows the inner `T`, without going through the container traits.
+    ///
+    /// Behaves identically to [`RefCell::try_borrow_mut`], and to [`try_get_mut`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is currently borrowed.
+    ///
+    /// [`try_get_mut`]: FragileTryMutContainer::try_get_mut
+    #[inline]
+    pub fn get_mut(&mut self) -> Result<RefMut<'_, T>, BorrowMutError> {
+        self.0.try_borrow_mut()
     }
 
+    /// Create another container sharing the same inner `T`, by cloning the `Rc`.
+    ///
+    /// Equivalent to [`Clone::clone`], but makes clear that the inner `T` is not cloned.
+    #[inline]
+    #[must_use]
+    pub fn clone_handle(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+
+    /// Immutably borrows the inner `T` and projects the borrow into a component of the `T`.
+    ///
+    /// Behaves identically to [`RefCell::try_borrow`] followed by [`Ref::map`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is currently mutably borrowed, in which case `f` is not
+    /// called.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::{CheckedRcRefCell, ContainerNew as _};
+    ///
+    /// let checked = CheckedRcRefCell::new_container((1_u8, "name"));
+    /// let name = checked.map_ref(|pair| &pair.1).unwrap();
+    /// assert_eq!(*name, "name");
+    /// ```
+    #[inline]
+    pub fn map_ref<U, F>(&self, f: F) -> Result<Ref<'_, U>, BorrowError>
+    where
+        U: ?Sized,
+        F: FnOnce(&T) -> &U,
+    {
+        self.0.try_borrow().map(|borrow| Ref::map(borrow, f))
+    }
+
+    /// Mutably borrows the inner `T` and projects the borrow into a component of the `T`.
+    ///
+    /// Behaves identically to [`RefCell::try_borrow_mut`] followed by [`RefMut::map`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is currently borrowed, in which case `f` is not called.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::{CheckedRcRefCell, ContainerNew as _};
+    ///
+    /// let mut checked = CheckedRcRefCell::new_container((1_u8, "name"));
+    /// *checked.map_mut(|pair| &mut pair.0).unwrap() += 1;
+    ///
+    /// let first = checked.map_ref(|pair| &pair.0).unwrap();
+    /// assert_eq!(*first, 2);
+    /// ```
+    #[inline]
+    pub fn map_mut<U, F>(&mut self, f: F) -> Result<RefMut<'_, U>, BorrowMutError>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        self.0.try_borrow_mut().map(|borrow| RefMut::map(borrow, f))
+    }
+}
+
+impl<T: ?Sized> FragileTryContainer<T> for CheckedRcRefCell<T> {
+    type Ref<'a>  = Ref<'a, T> where T: 'a;
+    type RefError = BorrowError;
+
     /// Attempt to retrieve the inner `T` from the container.
     /// Behaves identically to [`Rc::into_inner`].
     #[inline]
@@ -61,6 +201,20 @@ impl<T: ?Sized> FragileTryContainer<T> for CheckedRcRefCell<T> {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for CheckedRcRefCell<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Rc::new(RefCell::new(t)))
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for CheckedRcRefCell<T> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
+}
+
 impl<T: ?Sized> TryContainer<T> for CheckedRcRefCell<T> {}
 
 impl<T: ?Sized> FragileTryMutContainer<T> for CheckedRcRefCell<T> {
@@ -0,0 +1,220 @@
+use core::convert::Infallible;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::sync::Arc;
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+use crate::container_traits::{
+    ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer,
+};
+use super::HandlePoisonedResult as _;
+
+
+/// An `Arc<Mutex<T>>`-like container paired with a [`Condvar`], so that threads sharing the
+/// container can wait until the inner `T` satisfies some condition.
+///
+/// The container traits are implemented in the same way as for `Arc<Mutex<T>>`: borrowing the
+/// inner `T` locks the mutex, so this container is [fragile], and poison causes a panic. In
+/// addition, [`wait_while`] blocks until the inner `T` no longer satisfies a condition, and
+/// [`notify_one`] and [`notify_all`] wake up threads waiting in [`wait_while`]. Threads which
+/// change the inner `T` should notify waiting threads afterwards.
+///
+/// ## Examples
+/// A bounded queue:
+/// ```
+/// use std::{collections::VecDeque, thread};
+/// use generic_container::CondvarContainer;
+///
+/// const CAPACITY: usize = 2;
+///
+/// let queue = CondvarContainer::new(VecDeque::new());
+/// let producer_queue = queue.clone();
+///
+/// let producer = thread::spawn(move || {
+///     for item in 0..10_u32 {
+///         let mut items = producer_queue.wait_while(|items| items.len() >= CAPACITY);
+///         items.push_back(item);
+///         drop(items);
+///         producer_queue.notify_all();
+///     }
+/// });
+///
+/// let mut received = Vec::new();
+/// while received.len() < 10 {
+///     let mut items = queue.wait_while(VecDeque::is_empty);
+///     received.extend(items.drain(..));
+///     drop(items);
+///     queue.notify_all();
+/// }
+///
+/// producer.join().unwrap();
+/// assert_eq!(received, (0..10).collect::<Vec<_>>());
+/// ```
+///
+/// [`wait_while`]: CondvarContainer::wait_while
+/// [`notify_one`]: CondvarContainer::notify_one
+/// [`notify_all`]: CondvarContainer::notify_all
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub struct CondvarContainer<T: ?Sized>(Arc<(Condvar, Mutex<T>)>);
+
+impl<T> CondvarContainer<T> {
+    /// Create a new container around `t`, with a new [`Condvar`].
+    #[inline]
+    #[must_use]
+    pub fn new(t: T) -> Self {
+        Self(Arc::new((Condvar::new(), Mutex::new(t))))
+    }
+
+    /// Lock the mutex, and block the current thread until `condition` returns `false` for the
+    /// inner `T`.
+    ///
+    /// `condition` is checked immediately, and again whenever the current thread is woken up
+    /// by [`notify_one`] or [`notify_all`] (or spuriously). The returned guard holds the mutex.
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// Deadlocks if the current thread already holds the mutex, or if no other thread ever
+    /// changes the inner `T` to make `condition` return `false` and then notifies this container.
+    ///
+    /// [`notify_one`]: CondvarContainer::notify_one
+    /// [`notify_all`]: CondvarContainer::notify_all
+    #[inline]
+    pub fn wait_while<F: FnMut(&T) -> bool>(&self, mut condition: F) -> MutexGuard<'_, T> {
+        let (condvar, mutex) = &*self.0;
+        let guard = mutex.lock().panic_if_poisoned();
+        condvar.wait_while(guard, |t| condition(t)).panic_if_poisoned()
+    }
+}
+
+impl<T: ?Sized> CondvarContainer<T> {
+    /// Wake up one thread blocked in [`wait_while`] on this container (or a clone of it).
+    ///
+    /// [`wait_while`]: CondvarContainer::wait_while
+    #[inline]
+    pub fn notify_one(&self) {
+        self.0.0.notify_one();
+    }
+
+    /// Wake up every thread blocked in [`wait_while`] on this container (or a clone of it).
+    ///
+    /// [`wait_while`]: CondvarContainer::wait_while
+    #[inline]
+    pub fn notify_all(&self) {
+        self.0.0.notify_all();
+    }
+}
+
+impl<T: ?Sized> Clone for CondvarContainer<T> {
+    /// Create another container sharing the same inner `T` and [`Condvar`].
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: Default> Default for CondvarContainer<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for CondvarContainer<T> {
+    #[allow(clippy::missing_inline_in_public_items, reason = "not trivial or likely to be hot")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("CondvarContainer").field(&&self.0.1).finish()
+    }
+}
+
+impl<T: ?Sized> FragileTryContainer<T> for CondvarContainer<T> {
+    type Ref<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Arc::into_inner(self.0)
+            .map(|(_, mutex)| mutex.into_inner().ignore_poisoned())
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.0.1.lock().panic_if_poisoned())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for CondvarContainer<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(t)
+    }
+}
+
+impl<T: ?Sized> FragileContainer<T> for CondvarContainer<T> {
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// ## Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.0.1.lock().panic_if_poisoned()
+    }
+}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for CondvarContainer<T> {
+    type RefMut<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.0.1.lock().panic_if_poisoned())
+    }
+}
+
+impl<T: ?Sized> FragileMutContainer<T> for CondvarContainer<T> {
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self.0.1.lock().panic_if_poisoned()
+    }
+}
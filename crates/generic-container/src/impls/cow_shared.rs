@@ -0,0 +1,170 @@
+use core::convert::Infallible;
+use alloc::{rc::Rc, sync::Arc};
+
+use crate::container_traits::{
+    Container, ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, MutContainer, TryContainer, TryMutContainer,
+};
+
+
+/// A thin wrapper around `Rc<T>` or `Arc<T>` which provides mutable access to the inner `T` with
+/// clone-on-write semantics.
+///
+/// `Rc<T>` and `Arc<T>` only implement the immutable container traits. When `T: Clone`, this
+/// wrapper additionally implements [`MutContainer<T>`]: [`get_mut`] uses [`Rc::make_mut`] or
+/// [`Arc::make_mut`], which clone the inner `T` if other clones of the container exist, so that
+/// mutations are never visible through other clones. If this is the only clone, the `T` is
+/// mutated in place.
+///
+/// Since no locks or borrow flags are involved, `CowShared` containers are not
+/// [fragile](crate#fragility-potential-panics-or-deadlocks).
+///
+/// ## Examples
+/// ```
+/// use std::rc::Rc;
+/// use generic_container::{CowShared, FragileMutContainer};
+///
+/// let mut original = CowShared(Rc::new(vec![1, 2]));
+/// let snapshot = original.clone();
+///
+/// // The `Vec` is shared, so it is cloned before being mutated.
+/// FragileMutContainer::<Vec<i32>>::get_mut(&mut original).push(3);
+///
+/// assert_eq!(*original.0, [1, 2, 3]);
+/// assert_eq!(*snapshot.0, [1, 2]);
+/// ```
+///
+/// [`MutContainer<T>`]: MutContainer
+/// [`get_mut`]: FragileMutContainer::get_mut
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CowShared<C: ?Sized>(pub C);
+
+impl<T: ?Sized> FragileTryContainer<T> for CowShared<Rc<T>> {
+    type Ref<'a>  = &'a T where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    ///
+    /// Uses [`Rc::into_inner`].
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Rc::into_inner(self.0)
+    }
+
+    /// Infallibly get immutable access to the inner `T`.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for CowShared<Rc<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Rc::new(t))
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for CowShared<Rc<T>> {}
+
+impl<T: ?Sized> FragileContainer<T> for CowShared<Rc<T>> {
+    /// Infallibly get immutable access to the inner `T`.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> Container<T> for CowShared<Rc<T>> {}
+
+impl<T: Clone> FragileTryMutContainer<T> for CowShared<Rc<T>> {
+    type RefMut<'a>  = &'a mut T where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Infallibly get mutable access to the inner `T`, cloning it first if it is shared.
+    ///
+    /// Uses [`Rc::make_mut`].
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(Rc::make_mut(&mut self.0))
+    }
+}
+
+impl<T: Clone> TryMutContainer<T> for CowShared<Rc<T>> {}
+
+impl<T: Clone> FragileMutContainer<T> for CowShared<Rc<T>> {
+    /// Infallibly get mutable access to the inner `T`, cloning it first if it is shared.
+    ///
+    /// Uses [`Rc::make_mut`].
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        Rc::make_mut(&mut self.0)
+    }
+}
+
+impl<T: Clone> MutContainer<T> for CowShared<Rc<T>> {}
+
+impl<T: ?Sized> FragileTryContainer<T> for CowShared<Arc<T>> {
+    type Ref<'a>  = &'a T where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    ///
+    /// Uses [`Arc::into_inner`].
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Arc::into_inner(self.0)
+    }
+
+    /// Infallibly get immutable access to the inner `T`.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for CowShared<Arc<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Arc::new(t))
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for CowShared<Arc<T>> {}
+
+impl<T: ?Sized> FragileContainer<T> for CowShared<Arc<T>> {
+    /// Infallibly get immutable access to the inner `T`.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> Container<T> for CowShared<Arc<T>> {}
+
+impl<T: Clone> FragileTryMutContainer<T> for CowShared<Arc<T>> {
+    type RefMut<'a>  = &'a mut T where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Infallibly get mutable access to the inner `T`, cloning it first if it is shared.
+    ///
+    /// Uses [`Arc::make_mut`].
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(Arc::make_mut(&mut self.0))
+    }
+}
+
+impl<T: Clone> TryMutContainer<T> for CowShared<Arc<T>> {}
+
+impl<T: Clone> FragileMutContainer<T> for CowShared<Arc<T>> {
+    /// Infallibly get mutable access to the inner `T`, cloning it first if it is shared.
+    ///
+    /// Uses [`Arc::make_mut`].
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<T: Clone> MutContainer<T> for CowShared<Arc<T>> {}
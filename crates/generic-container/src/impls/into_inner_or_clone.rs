@@ -0,0 +1,164 @@
+#[cfg(any(feature = "alloc", doc))]
+use core::cell::RefCell;
+#[cfg(any(feature = "alloc", doc))]
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+#[cfg(any(feature = "std", doc))]
+use std::sync::{Mutex, RwLock};
+
+#[cfg(feature = "parking-lot")]
+use parking_lot::ReentrantMutex;
+
+#[cfg(any(feature = "std", doc))]
+use super::HandlePoisonedResult as _;
+
+
+/// Trait for taking the inner `T` out of a container, cloning it if the container is shared.
+///
+/// [`FragileTryContainer::into_inner`] returns `None` if other clones of an `Rc`- or `Arc`-based
+/// container exist. When `T` is [`Clone`], [`into_inner_or_clone`] instead falls back to cloning
+/// the shared `T`, so it always returns a value. If this is the only clone of the container, the
+/// inner `T` is moved out without being cloned.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// The containers which use interior mutability (such as `Rc<RefCell<T>>` or `Arc<Mutex<T>>`)
+/// borrow their `T` with [`get_ref`] before cloning it, and so are [fragile] in the same way as
+/// their [`FragileContainer`] implementations.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::IntoInnerOrClone as _;
+///
+/// let container = Arc::new(Mutex::new(vec![1, 2]));
+/// let clone = Arc::clone(&container);
+///
+/// // Another clone exists, so the `Vec` is cloned.
+/// let cloned: Vec<i32> = container.into_inner_or_clone();
+/// // Now this is the only clone, so the `Vec` is moved out.
+/// let moved: Vec<i32> = clone.into_inner_or_clone();
+/// assert_eq!(cloned, moved);
+/// ```
+///
+/// [`FragileTryContainer::into_inner`]: crate::FragileTryContainer::into_inner
+/// [`FragileContainer`]: crate::FragileContainer
+/// [`get_ref`]: crate::FragileContainer::get_ref
+/// [`into_inner_or_clone`]: IntoInnerOrClone::into_inner_or_clone
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait IntoInnerOrClone<T> {
+    /// Move the inner `T` out of the container if this is its only clone, and otherwise return
+    /// a clone of the inner `T`.
+    ///
+    /// For lock-based containers, poison errors are ignored when moving the `T` out, but cause a
+    /// panic when cloning it (matching their [`into_inner`] and [`get_ref`] implementations).
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`into_inner`]: crate::FragileTryContainer::into_inner
+    /// [`get_ref`]: crate::FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[must_use]
+    fn into_inner_or_clone(self) -> T where T: Clone;
+}
+
+impl<T> IntoInnerOrClone<T> for T {
+    /// Returns `self`, without cloning it.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        self
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T> IntoInnerOrClone<T> for Box<T> {
+    /// Moves the `T` out of the box, without cloning it.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        *self
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T> IntoInnerOrClone<T> for Rc<T> {
+    /// Behaves identically to [`Rc::unwrap_or_clone`].
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::unwrap_or_clone(self)
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T> IntoInnerOrClone<T> for Arc<T> {
+    /// Behaves identically to [`Arc::unwrap_or_clone`].
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::unwrap_or_clone(self)
+    }
+}
+
+#[cfg(any(feature = "alloc", doc))]
+impl<T> IntoInnerOrClone<T> for Rc<RefCell<T>> {
+    /// Uses [`RefCell::borrow`] if the `T` needs to be cloned.
+    ///
+    /// ## Panics
+    /// Panics if the contract of a fragile container is broken.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::try_unwrap(self).map_or_else(
+            |shared| shared.borrow().clone(),
+            RefCell::into_inner,
+        )
+    }
+}
+
+#[cfg(any(feature = "std", doc))]
+impl<T> IntoInnerOrClone<T> for Arc<Mutex<T>> {
+    /// Uses [`Mutex::lock`] if the `T` needs to be cloned.
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered while cloning the `T`, which can only occur if
+    /// another thread has already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::try_unwrap(self).map_or_else(
+            |shared| shared.lock().panic_if_poisoned().clone(),
+            |mutex| mutex.into_inner().ignore_poisoned(),
+        )
+    }
+}
+
+#[cfg(any(feature = "std", doc))]
+impl<T> IntoInnerOrClone<T> for Arc<RwLock<T>> {
+    /// Uses [`RwLock::read`] if the `T` needs to be cloned.
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered while cloning the `T`, which can only occur if
+    /// another thread has already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::try_unwrap(self).map_or_else(
+            |shared| shared.read().panic_if_poisoned().clone(),
+            |rwlock| rwlock.into_inner().ignore_poisoned(),
+        )
+    }
+}
+
+#[cfg(feature = "parking-lot")]
+impl<T> IntoInnerOrClone<T> for Arc<ReentrantMutex<T>> {
+    /// Uses [`ReentrantMutex::lock`] if the `T` needs to be cloned.
+    ///
+    /// # Deadlocks
+    /// May deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn into_inner_or_clone(self) -> T where T: Clone {
+        Self::try_unwrap(self).map_or_else(
+            |shared| shared.lock().clone(),
+            ReentrantMutex::into_inner,
+        )
+    }
+}
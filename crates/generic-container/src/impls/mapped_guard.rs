@@ -0,0 +1,63 @@
+#![expect(unsafe_code, reason = "a projected reference is stored alongside its guard")]
+
+use core::ptr::NonNull;
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+
+
+/// A lock guard which has been projected into a component `U` of the data it protects, as
+/// returned by [`ProjectableContainer::map_ref`] for lock-based containers.
+///
+/// The standard library's lock guards cannot yet be mapped on stable Rust, so this type keeps the
+/// original guard alive for as long as the projected reference is in use. The lock is released
+/// when the `MappedGuard` is dropped.
+///
+/// [`ProjectableContainer::map_ref`]: crate::ProjectableContainer::map_ref
+pub struct MappedGuard<G, U: ?Sized> {
+    /// Points into the data protected by `guard`.
+    value: NonNull<U>,
+    /// Kept alive so that `value` remains valid.
+    #[expect(dead_code, reason = "the guard is only held for its destructor")]
+    guard: G,
+}
+
+impl<G: Deref, U: ?Sized> MappedGuard<G, U> {
+    /// Project `guard` into a component of its protected data.
+    ///
+    /// The `guard` must dereference to data which is not stored inline in the guard itself, so
+    /// that moving the guard does not move the data. This holds for the standard library's lock
+    /// guards, which only reference the lock.
+    #[inline]
+    pub(crate) fn new<F: FnOnce(&G::Target) -> &U>(guard: G, f: F) -> Self {
+        let value = NonNull::from(f(&guard));
+        Self { value, guard }
+    }
+}
+
+impl<G, U: ?Sized> Deref for MappedGuard<G, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `value` was derived from a shared borrow of the data protected by `guard`,
+        // which is not stored inline in `guard` (as required by `Self::new`), and so remains
+        // valid and immutably borrowed for as long as `guard` is alive.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<G, U: ?Sized + Debug> Debug for MappedGuard<G, U> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<G, U: ?Sized + Display> Display for MappedGuard<G, U> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&**self, f)
+    }
+}
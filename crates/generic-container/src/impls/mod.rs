@@ -3,9 +3,14 @@
 #![warn(clippy::missing_inline_in_public_items)]
 
 mod t_itself;
+mod reference;
+mod into_inner_or_clone;
+mod assume_non_fragile;
 #[cfg(any(feature = "alloc", doc))]
 mod box_container;
 #[cfg(any(feature = "alloc", doc))]
+mod pin_box;
+#[cfg(any(feature = "alloc", doc))]
 mod rc;
 #[cfg(any(feature = "alloc", doc))]
 mod arc;
@@ -13,20 +18,66 @@ mod arc;
 mod rc_refcell;
 #[cfg(any(feature = "alloc", doc))]
 mod checked_rc_refcell;
+#[cfg(any(feature = "alloc", doc))]
+mod cow_shared;
 
+#[cfg(any(feature = "std", doc))]
+mod mutex;
+#[cfg(any(feature = "std", doc))]
+mod rwlock;
 #[cfg(any(feature = "std", doc))]
 mod arc_rwlock;
 #[cfg(any(feature = "std", doc))]
 mod arc_mutex;
+#[cfg(any(feature = "std", doc))]
+mod checked_arc_mutex;
+#[cfg(any(feature = "std", doc))]
+mod poison_tolerant;
+#[cfg(any(feature = "std", doc))]
+mod condvar_container;
+#[cfg(any(feature = "std", doc))]
+mod mapped_guard;
 
+#[cfg(feature = "thread-checked-lock")]
+mod checked_mutex;
 #[cfg(feature = "thread-checked-lock")]
 mod arc_checked_mutex;
 
+#[cfg(feature = "tokio")]
+mod arc_tokio_mutex;
+#[cfg(feature = "tokio")]
+mod arc_tokio_rwlock;
 
+#[cfg(feature = "async-lock")]
+mod arc_async_mutex;
+#[cfg(feature = "async-lock")]
+mod arc_async_rwlock;
+
+#[cfg(feature = "parking-lot")]
+mod arc_reentrant_mutex;
+
+#[cfg(feature = "blocking-unwrap")]
+mod blocking_unwrap;
+
+
+pub use self::into_inner_or_clone::IntoInnerOrClone;
+pub use self::assume_non_fragile::AssumeNonFragile;
 #[cfg(any(feature = "alloc", doc))]
 pub use self::checked_rc_refcell::CheckedRcRefCell;
+#[cfg(any(feature = "alloc", doc))]
+pub use self::cow_shared::CowShared;
+#[cfg(any(feature = "std", doc))]
+pub use self::checked_arc_mutex::{CheckedArcMutex, ErasedTryLockError};
+#[cfg(any(feature = "std", doc))]
+pub use self::poison_tolerant::PoisonTolerant;
+#[cfg(any(feature = "std", doc))]
+pub use self::condvar_container::CondvarContainer;
+#[cfg(any(feature = "std", doc))]
+pub use self::mapped_guard::MappedGuard;
 #[cfg(feature = "thread-checked-lock")]
 pub use self::arc_checked_mutex::ErasedLockError;
+#[cfg(feature = "blocking-unwrap")]
+pub use self::blocking_unwrap::BlockingUnwrap;
 
 
 #[cfg(any(feature = "std", doc))]
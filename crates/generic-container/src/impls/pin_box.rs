@@ -0,0 +1,78 @@
+use core::{convert::Infallible, pin::Pin};
+use alloc::boxed::Box;
+
+use crate::container_traits::{
+    Container, ContainerNew, ContainerPointer, FragileContainer, FragileMutContainer,
+    FragileTryContainer, FragileTryMutContainer, MutContainer, TryContainer, TryMutContainer,
+};
+
+
+// The `T` in a `Pin<Box<T>>` cannot be moved out, and mutable access is only provided if the `T`
+// is `Unpin`, in which case pinning has no effect.
+
+impl<T: ?Sized> FragileTryContainer<T> for Pin<Box<T>> {
+    type Ref<'a>  = &'a T where T: 'a;
+    type RefError = Infallible;
+
+    /// Always returns `None`, as a pinned `T` cannot be moved out of its box.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        None
+    }
+
+    /// Infallibly get immutable access to the inner `T`, with [`Pin::get_ref`].
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.as_ref().get_ref())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for Pin<Box<T>> {
+    /// Behaves identically to [`Box::pin`].
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Box::pin(t)
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for Pin<Box<T>> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        &raw const **self
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for Pin<Box<T>> {}
+
+impl<T: ?Sized> FragileContainer<T> for Pin<Box<T>> {
+    /// Infallibly get immutable access to the inner `T`, with [`Pin::get_ref`].
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.as_ref().get_ref()
+    }
+}
+
+impl<T: ?Sized> Container<T> for Pin<Box<T>> {}
+
+impl<T: ?Sized + Unpin> FragileTryMutContainer<T> for Pin<Box<T>> {
+    type RefMut<'a>  = &'a mut T where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Infallibly get mutable access to the inner `T`, with [`Pin::get_mut`].
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.as_mut().get_mut())
+    }
+}
+
+impl<T: ?Sized + Unpin> TryMutContainer<T> for Pin<Box<T>> {}
+
+impl<T: ?Sized + Unpin> FragileMutContainer<T> for Pin<Box<T>> {
+    /// Infallibly get mutable access to the inner `T`, with [`Pin::get_mut`].
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self.as_mut().get_mut()
+    }
+}
+
+impl<T: ?Sized + Unpin> MutContainer<T> for Pin<Box<T>> {}
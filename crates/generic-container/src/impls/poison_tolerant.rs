@@ -0,0 +1,227 @@
+use core::convert::Infallible;
+use alloc::sync::Arc;
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::container_traits::{
+    ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer,
+};
+use super::HandlePoisonedResult as _;
+
+
+/// A thin wrapper around `Arc<Mutex<T>>` or `Arc<RwLock<T>>` which implements the container
+/// traits in the same way, except that poison is ignored instead of causing a panic.
+///
+/// The container traits' errors cannot borrow from the container, so a poison error (which holds
+/// a lock guard) cannot be returned by [`try_get_ref`] or [`try_get_mut`]. Instead, when the lock
+/// is poisoned, the guard is recovered from the poison error and returned, and the lock remains
+/// poisoned. Use [`Mutex::is_poisoned`] or [`Mutex::clear_poison`] (or the [`RwLock`] versions)
+/// on the inner lock to detect or clear poison.
+///
+/// This is useful for long-running services which should keep using shared state after a
+/// worker thread panics. Since the data may have been left in an inconsistent state by the
+/// panicking thread, use this wrapper only if the data's invariants cannot be broken by an
+/// interrupted update.
+///
+/// Like the wrapped containers, `PoisonTolerant` containers are
+/// [fragile](crate#fragility-potential-panics-or-deadlocks).
+///
+/// ## Examples
+/// ```
+/// use std::{panic, sync::{Arc, Mutex}};
+/// use generic_container::{FragileMutContainer, PoisonTolerant};
+///
+/// let container = PoisonTolerant(Arc::new(Mutex::new(0_u32)));
+/// let clone = container.clone();
+///
+/// let _ = panic::catch_unwind(move || {
+///     let _guard = clone.0.lock().unwrap();
+///     panic!("poison the mutex");
+/// });
+/// assert!(container.0.is_poisoned());
+///
+/// let mut container = container;
+/// *FragileMutContainer::<u32>::get_mut(&mut container) += 1;
+/// assert_eq!(*container.0.lock().unwrap_or_else(|poison| poison.into_inner()), 1);
+/// ```
+///
+/// [`try_get_ref`]: FragileTryContainer::try_get_ref
+/// [`try_get_mut`]: FragileTryMutContainer::try_get_mut
+#[derive(Default, Debug, Clone, Copy)]
+pub struct PoisonTolerant<C: ?Sized>(pub C);
+
+impl<T: ?Sized> FragileTryContainer<T> for PoisonTolerant<Arc<Mutex<T>>> {
+    type Ref<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Arc::into_inner(self.0)
+            .map(Mutex::into_inner)
+            .map(Result::ignore_poisoned)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.0.lock().ignore_poisoned())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for PoisonTolerant<Arc<Mutex<T>>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Arc::new(Mutex::new(t)))
+    }
+}
+
+impl<T: ?Sized> FragileContainer<T> for PoisonTolerant<Arc<Mutex<T>>> {
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.0.lock().ignore_poisoned()
+    }
+}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for PoisonTolerant<Arc<Mutex<T>>> {
+    type RefMut<'a>  = MutexGuard<'a, T> where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.0.lock().ignore_poisoned())
+    }
+}
+
+impl<T: ?Sized> FragileMutContainer<T> for PoisonTolerant<Arc<Mutex<T>>> {
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`Mutex::lock`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self.0.lock().ignore_poisoned()
+    }
+}
+
+impl<T: ?Sized> FragileTryContainer<T> for PoisonTolerant<Arc<RwLock<T>>> {
+    type Ref<'a>  = RwLockReadGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Attempt to retrieve the inner `T` from the container.
+    /// Behaves identically to [`Arc::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Arc::into_inner(self.0)
+            .map(RwLock::into_inner)
+            .map(Result::ignore_poisoned)
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.0.read().ignore_poisoned())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for PoisonTolerant<Arc<RwLock<T>>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self(Arc::new(RwLock::new(t)))
+    }
+}
+
+impl<T: ?Sized> FragileContainer<T> for PoisonTolerant<Arc<RwLock<T>>> {
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.0.read().ignore_poisoned()
+    }
+}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for PoisonTolerant<Arc<RwLock<T>>> {
+    type RefMut<'a>  = RwLockWriteGuard<'a, T> where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::write`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self.0.write().ignore_poisoned())
+    }
+}
+
+impl<T: ?Sized> FragileMutContainer<T> for PoisonTolerant<Arc<RwLock<T>>> {
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::write`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// Ignores any poison errors.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self.0.write().ignore_poisoned()
+    }
+}
@@ -1,21 +1,21 @@
 use core::convert::Infallible;
 use core::cell::{Ref, RefCell, RefMut};
-use alloc::rc::Rc;
+use alloc::rc::{Rc, Weak};
 
 use crate::container_traits::{
-    FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    ContainerNew, ContainerPointer, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, ProjectableContainer, WeakContainer,
 };
 
 
+// Note that `Rc<RefCell<T>>` deliberately implements neither `TryContainer` nor `TryMutContainer`,
+// as it is fragile. Its `try_*` methods panic instead of returning errors; `CheckedRcRefCell<T>`
+// is the non-fragile counterpart, with `BorrowError` and `BorrowMutError` errors.
+
 impl<T: ?Sized> FragileTryContainer<T> for Rc<RefCell<T>> {
     type Ref<'a>  = Ref<'a, T> where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where T: Sized {
-        Self::new(RefCell::new(t))
-    }
-
     /// Attempt to retrieve the inner `T` from the container.
     /// Behaves identically to [`Rc::into_inner`].
     #[inline]
@@ -26,16 +26,47 @@ impl<T: ?Sized> FragileTryContainer<T> for Rc<RefCell<T>> {
     /// Get immutable access to the inner `T`.
     ///
     /// Uses [`RefCell::borrow`], so this container is
-    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks). To instead get an error from
+    /// [`RefCell::try_borrow`], wrap the container in a [`CheckedRcRefCell`].
     ///
     /// ## Panics
     /// Panics if the contract of a fragile container is broken.
+    ///
+    /// [`CheckedRcRefCell`]: crate::CheckedRcRefCell
     #[inline]
     fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
         Ok(self.borrow())
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for Rc<RefCell<T>> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(RefCell::new(t))
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for Rc<RefCell<T>> {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        RefCell::as_ptr(self)
+    }
+}
+
+impl<T: ?Sized> WeakContainer<T> for Rc<RefCell<T>> {
+    type Weak = Weak<RefCell<T>>;
+
+    #[inline]
+    fn downgrade(&self) -> Self::Weak {
+        Self::downgrade(self)
+    }
+
+    #[inline]
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
 impl<T: ?Sized> FragileContainer<T> for Rc<RefCell<T>> {
     /// Get immutable access to the inner `T`.
     ///
@@ -50,6 +81,23 @@ impl<T: ?Sized> FragileContainer<T> for Rc<RefCell<T>> {
     }
 }
 
+impl<T: ?Sized> ProjectableContainer<T> for Rc<RefCell<T>> {
+    type MappedRef<'a, U: ?Sized + 'a> = Ref<'a, U> where T: 'a;
+
+    /// Borrows the inner `T` with [`RefCell::borrow`], and maps the borrow with [`Ref::map`].
+    ///
+    /// # Panics
+    /// Panics if the contract of a fragile container is broken.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        Ref::map(self.borrow(), f)
+    }
+}
+
 impl<T: ?Sized> FragileTryMutContainer<T> for Rc<RefCell<T>> {
     type RefMut<'a>  = RefMut<'a, T> where T: 'a;
     type RefMutError = Infallible;
@@ -57,10 +105,13 @@ impl<T: ?Sized> FragileTryMutContainer<T> for Rc<RefCell<T>> {
     /// Get mutable access to the inner `T`.
     ///
     /// Uses [`RefCell::borrow_mut`], so this container is
-    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks). To instead get an error from
+    /// [`RefCell::try_borrow_mut`], wrap the container in a [`CheckedRcRefCell`].
     ///
     /// ## Panics
     /// Panics if the contract of a fragile container is broken.
+    ///
+    /// [`CheckedRcRefCell`]: crate::CheckedRcRefCell
     #[inline]
     fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
         Ok(self.borrow_mut())
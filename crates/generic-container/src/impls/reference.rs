@@ -0,0 +1,132 @@
+use core::convert::Infallible;
+
+use crate::container_traits::{
+    Container, ContainerPointer, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, MutContainer, ProjectableContainer, TryContainer, TryMutContainer,
+};
+
+
+// Note that references do not implement `ContainerNew`, as they cannot own their `T`.
+
+impl<T: ?Sized> FragileTryContainer<T> for &T {
+    type Ref<'a>  = &'a T where Self: 'a;
+    type RefError = Infallible;
+
+    /// Always returns `None`, as the `T` cannot be moved out of a shared reference.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        None
+    }
+
+    /// Infallibly get immutable access to the referenced `T`.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self)
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for &T {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        &raw const **self
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for &T {}
+
+impl<T: ?Sized> FragileContainer<T> for &T {
+    /// Infallibly get immutable access to the referenced `T`.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self
+    }
+}
+
+impl<T: ?Sized> ProjectableContainer<T> for &T {
+    type MappedRef<'a, U: ?Sized + 'a> = &'a U where Self: 'a;
+
+    /// Maps the reference to the inner `T` with `f`.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        f(self)
+    }
+}
+
+impl<T: ?Sized> Container<T> for &T {}
+
+impl<T: ?Sized> FragileTryContainer<T> for &mut T {
+    type Ref<'a>  = &'a T where Self: 'a;
+    type RefError = Infallible;
+
+    /// Always returns `None`, as the `T` cannot be moved out of a reference.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        None
+    }
+
+    /// Infallibly get immutable access to the referenced `T`.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self)
+    }
+}
+
+impl<T: ?Sized> ContainerPointer<T> for &mut T {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        &raw const **self
+    }
+}
+
+impl<T: ?Sized> TryContainer<T> for &mut T {}
+
+impl<T: ?Sized> FragileContainer<T> for &mut T {
+    /// Infallibly get immutable access to the referenced `T`.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self
+    }
+}
+
+impl<T: ?Sized> ProjectableContainer<T> for &mut T {
+    type MappedRef<'a, U: ?Sized + 'a> = &'a U where Self: 'a;
+
+    /// Maps the reference to the inner `T` with `f`.
+    #[inline]
+    fn map_ref<'a, U, F>(&'a self, f: F) -> Self::MappedRef<'a, U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        f(self)
+    }
+}
+
+impl<T: ?Sized> Container<T> for &mut T {}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for &mut T {
+    type RefMut<'a>  = &'a mut T where Self: 'a;
+    type RefMutError = Infallible;
+
+    /// Infallibly get mutable access to the referenced `T`.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(self)
+    }
+}
+
+impl<T: ?Sized> TryMutContainer<T> for &mut T {}
+
+impl<T: ?Sized> FragileMutContainer<T> for &mut T {
+    /// Infallibly get mutable access to the referenced `T`.
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        self
+    }
+}
+
+impl<T: ?Sized> MutContainer<T> for &mut T {}
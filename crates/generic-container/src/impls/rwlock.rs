@@ -0,0 +1,99 @@
+use core::convert::Infallible;
+use std::sync::{RwLock, RwLockReadGuard};
+
+use crate::container_traits::{
+    ContainerNew, FragileContainer, FragileMutContainer, FragileTryContainer,
+    FragileTryMutContainer, StrictContainer,
+};
+use super::HandlePoisonedResult as _;
+
+
+// A `RwLock<T>` is not `Clone`, so it acts like a `Box<T>` rather than an `Arc<RwLock<T>>`. Mutable
+// access does not need to lock the `RwLock`, as `&mut self` already guarantees exclusive access;
+// only immutable access is fragile.
+
+impl<T: ?Sized> FragileTryContainer<T> for RwLock<T> {
+    type Ref<'a>  = RwLockReadGuard<'a, T> where T: 'a;
+    type RefError = Infallible;
+
+    /// Retrieve the inner `T` from the container. Always returns `Some`.
+    /// Behaves identically to [`RwLock::into_inner`].
+    ///
+    /// Ignores any poison errors.
+    #[inline]
+    fn into_inner(self) -> Option<T> where T: Sized {
+        Some(Self::into_inner(self).ignore_poisoned())
+    }
+
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// # Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn try_get_ref(&self) -> Result<Self::Ref<'_>, Self::RefError> {
+        Ok(self.read().panic_if_poisoned())
+    }
+}
+
+impl<T: ?Sized> ContainerNew<T> for RwLock<T> {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        Self::new(t)
+    }
+}
+
+impl<T: ?Sized> StrictContainer<T> for RwLock<T> {}
+
+impl<T: ?Sized> FragileContainer<T> for RwLock<T> {
+    /// Get immutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::read`], so this container is
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks).
+    ///
+    /// ## Panics and Deadlocks
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    ///
+    /// May also panic or deadlock if the contract of a fragile container is broken.
+    #[inline]
+    fn get_ref(&self) -> Self::Ref<'_> {
+        self.read().panic_if_poisoned()
+    }
+}
+
+impl<T: ?Sized> FragileTryMutContainer<T> for RwLock<T> {
+    type RefMut<'a>  = &'a mut T where T: 'a;
+    type RefMutError = Infallible;
+
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::get_mut`], which does not lock the `RwLock`.
+    ///
+    /// # Panics
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    #[inline]
+    fn try_get_mut(&mut self) -> Result<Self::RefMut<'_>, Self::RefMutError> {
+        Ok(Self::get_mut(self).panic_if_poisoned())
+    }
+}
+
+impl<T: ?Sized> FragileMutContainer<T> for RwLock<T> {
+    /// Get mutable access to the inner `T`.
+    ///
+    /// Uses [`RwLock::get_mut`], which does not lock the `RwLock`.
+    ///
+    /// # Panics
+    /// Panics if a poison error is encountered, which can only occur if another thread has
+    /// already panicked.
+    #[inline]
+    fn get_mut(&mut self) -> Self::RefMut<'_> {
+        Self::get_mut(self).panic_if_poisoned()
+    }
+}
@@ -1,8 +1,9 @@
 use core::convert::Infallible;
 
 use crate::container_traits::{
-    Container, FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
-    MutContainer, TryContainer, TryMutContainer,
+    Container, ContainerNew, ContainerPointer, FragileContainer, FragileMutContainer,
+    FragileTryContainer, FragileTryMutContainer, MutContainer, StrictContainer, TryContainer,
+    TryMutContainer,
 };
 
 
@@ -10,11 +11,6 @@ impl<T: ?Sized> FragileTryContainer<T> for T {
     type Ref<'a>  = &'a T where T: 'a;
     type RefError = Infallible;
 
-    #[inline]
-    fn new_container(t: T) -> Self where Self: Sized {
-        t
-    }
-
     /// Infallibly get the `T`.
     #[inline]
     fn into_inner(self) -> Option<T> where Self: Sized {
@@ -28,6 +24,22 @@ impl<T: ?Sized> FragileTryContainer<T> for T {
     }
 }
 
+impl<T: ?Sized> ContainerNew<T> for T {
+    #[inline]
+    fn new_container(t: T) -> Self where T: Sized {
+        t
+    }
+}
+
+impl<T: ?Sized> StrictContainer<T> for T {}
+
+impl<T: ?Sized> ContainerPointer<T> for T {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self
+    }
+}
+
 impl<T: ?Sized> TryContainer<T> for T {}
 
 impl<T: ?Sized> FragileContainer<T> for T {
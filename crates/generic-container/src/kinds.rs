@@ -17,6 +17,12 @@
 //! }
 //! ```
 //!
+//! Containers which hold their `T` directly, such as `Box<T>`, `Rc<T>`, and `Arc<T>`, can instead
+//! be created from an unsized `T` by way of [`ContainerFromBox`], whose `from_boxed` method accepts
+//! a `Box<T>` (which may itself be created by unsizing coercion). In particular, a container of a
+//! slice, such as `Arc<[T]>`, can be created from a `Vec<T>` by way of `Vec::into_boxed_slice`;
+//! [`GenericContainer::from_vec`] does exactly that.
+//!
 //! If some data needs thread-safe mutability, but you don't want to pay the cost of a lock for
 //! read-only data, you can use multiple GATs:
 //! ```
@@ -48,11 +54,119 @@
 //! provided. They do not use [`Dupe`] bounds, and do not mess with type-equality shenanigans that
 //! confuse the trait solver.
 //!
+//! Where a kind trait's requirements imply another's, a blanket implementation lets a kind of the
+//! stricter trait be used where the looser trait is required, with the same containers:
+//! - every [`TLike`] kind is a [`FragileTLike`] kind,
+//! - every [`BoxLike`] kind is a [`TLike`] kind and a [`FragileBoxLike`] kind,
+//! - every [`RcLike`] kind is a [`FragileRcLike`] kind,
+//! - every [`ArcLike`] kind is a [`FragileArcLike`] kind, and
+//! - every [`ArcMutexLike`] kind is an [`ArcRwLockLike`] kind.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use generic_container::FragileContainer;
+//! use generic_container::kinds::{FragileRcLike, RcKind};
+//!
+//! fn shared_total<K: FragileRcLike>(values: &[u32]) -> u32 {
+//!     let total = <K as FragileRcLike>::wrap(values.iter().sum::<u32>());
+//!     let clone = total.clone();
+//!     *clone.get_ref()
+//! }
+//!
+//! // `RcKind` only implements `RcLike` directly.
+//! assert_eq!(shared_total::<RcKind>(&[1, 2, 3]), 6);
+//! # }
+//! ```
+//!
+//! A trait can only have one such blanket implementation without the implementations
+//! overlapping, so the following are omitted, and kinds must implement the looser trait
+//! themselves:
+//! - [`FragileBoxLike`] implies [`FragileTLike`], but would overlap with [`TLike`],
+//! - [`RcRefCellLike`] implies [`FragileRcLike`], but would overlap with [`RcLike`], and
+//! - [`ArcRwLockLike`] implies [`FragileArcLike`], but would overlap with [`ArcLike`].
+//!
+//! Kind traits whose containers support fewer inner types cannot imply a kind trait whose
+//! containers support more, even where the containers are otherwise similar. For instance,
+//! [`ArcLike`] requires `T: Send + Sync` but [`RcLike`] does not, so kinds such as `ArcKind` must
+//! implement [`RcLike`] themselves. Likewise, the [`ArcThreadCheckedMutexLike`] and
+//! [`CheckedRcRefCellLike`] pair and the [`ArcCowLike`] and [`RcCowLike`] pair have no blanket
+//! implementations between them.
+//!
+//! ## Wrapping values
+//!
+//! Each kind trait has a `wrap` function, which is shorthand for the `new_container` function of
+//! its container (for instance, [`ContainerNew::new_container`]), intended for code which is
+//! generic over the kind. A kind usually implements several kind traits, either directly or
+//! through the blanket implementations above, so `K::wrap` is ambiguous whenever more than one of
+//! `K`'s kind traits is in scope or in `K`'s bounds. Name the trait to disambiguate:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use generic_container::kinds::{ArcKind, ArcLike, FragileArcLike};
+//!
+//! // `ArcKind::wrap(1_u8)` would be ambiguous here.
+//! let shared = <ArcKind as ArcLike>::wrap(1_u8);
+//! assert_eq!(*shared, 1);
+//! # }
+//! ```
+//!
+//! With the `alloc` feature, the kind traits whose containers are [`FragileContainer`]s also have
+//! a `new_dyn` function, which wraps a value and boxes the container as a `Box<dyn
+//! DynContainer<T>>`. Every container is also a container of itself, so calling
+//! [`DynContainer::boxed`] on a wrapped value is ambiguous unless the `T` of the
+//! `DynContainer<T>` is written out; `new_dyn` fixes it to the wrapped value's type:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use generic_container::kinds::{ArcKind, ArcLike};
+//!
+//! let name = ArcKind::new_dyn("finch".to_owned());
+//! assert_eq!(**(*name).get_ref_dyn(), "finch");
+//! # }
+//! ```
+//!
+//! ## Fragility
+//!
+//! Each kind trait whose containers are permitted to be
+//! [fragile](crate#fragility-potential-panics-or-deadlocks) has an `IS_FRAGILE` associated
+//! constant, which defaults to `true` and should be overridden by kinds whose containers are not
+//! fragile. It is purely informational, for instance for diagnostics, and is not a guarantee;
+//! nothing checks it against the kind's containers.
+//!
+//! Kind traits which require containers that are not fragile have no such constant, and their
+//! blanket implementations of looser traits set it to `false`. The exceptions are
+//! [`CheckedRcRefCellLike`] and [`ArcThreadCheckedMutexLike`], whose fallible containers have no
+//! fragile counterpart trait; they have an `IS_FRAGILE` constant which defaults to `false`, so
+//! that code which is generic over them can still read it.
+//!
+//! ```
+//! use generic_container::kinds::{ArcKind, ArcMutexKind, FragileArcLike};
+//!
+//! fn describe<K: FragileArcLike>() -> &'static str {
+//!     if K::IS_FRAGILE { "may panic or deadlock if misused" } else { "not fragile" }
+//! }
+//!
+//! assert_eq!(describe::<ArcKind>(), "not fragile");
+//! assert_eq!(describe::<ArcMutexKind>(), "may panic or deadlock if misused");
+//! ```
+//!
 //! [`Dupe`]: https://docs.rs/dupe/0.9/dupe/trait.Dupe.html
+//! [`ContainerFromBox`]: crate::ContainerFromBox
+//! [`DynContainer::boxed`]: crate::DynContainer::boxed
+//! [`FragileContainer`]: crate::FragileContainer
+//! [`GenericContainer::from_vec`]: crate::GenericContainer::from_vec
+
+#[cfg(any(feature = "alloc", doc))]
+use alloc::boxed::Box;
 
+use crate::async_container::{AsyncContainerNew, AsyncMutContainer};
+#[cfg(target_has_atomic = "64")]
+use crate::atomic_container::AtomicContainerNew;
 use crate::container_traits::{
-    Container, FragileContainer, FragileMutContainer, MutContainer, TryMutContainer,
+    Container, ContainerNew, FragileContainer, FragileMutContainer, MutContainer, TryMutContainer,
 };
+#[cfg(any(feature = "alloc", doc))]
+use crate::dyn_container::DynContainer;
 
 
 // ================================
@@ -63,8 +177,27 @@ use crate::container_traits::{
 ///
 /// Has strictly looser requirements than [`FragileTLike`].
 pub trait TLike {
-    /// A `T`-like container type
-    type Container<T>: MutContainer<T>;
+    /// A `T`-like container type.
+    type Container<T>: MutContainer<T> + ContainerNew<T>;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
 }
 
 /// A [container kind trait](self) based on how a type `T` acts as a container for itself.
@@ -74,15 +207,68 @@ pub trait TLike {
 pub trait FragileTLike {
     /// A `T`-like container type, but permitted to be
     /// [fragile](crate#fragility-potential-panics-or-deadlocks).
-    type Container<T>: FragileMutContainer<T>;
+    type Container<T>: FragileMutContainer<T> + ContainerNew<T>;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`TLike`] kind is a [`FragileTLike`] kind, with the same containers.
+impl<K: TLike> FragileTLike for K {
+    type Container<T> = <K as TLike>::Container<T>;
+
+    const IS_FRAGILE: bool = false;
 }
 
 /// A [container kind trait](self) based on how `Box<T>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`TLike`], [`FragileTLike`], and [`FragileBoxLike`].
 pub trait BoxLike {
-    /// A `Box<T>`-like container type
-    type Container<T: ?Sized>: MutContainer<T>;
+    /// A `Box<T>`-like container type.
+    type Container<T: ?Sized>: MutContainer<T> + ContainerNew<T>;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`BoxLike`] kind is a [`TLike`] kind, with the same containers.
+impl<K: BoxLike> TLike for K {
+    type Container<T> = <K as BoxLike>::Container<T>;
 }
 
 /// A [container kind trait](self) based on how `Box<T>` acts as a container for `T`.
@@ -94,15 +280,63 @@ pub trait BoxLike {
 pub trait FragileBoxLike {
     /// A `Box<T>`-like container type, but permitted to be
     /// [fragile](crate#fragility-potential-panics-or-deadlocks).
-    type Container<T: ?Sized>: FragileMutContainer<T>;
+    type Container<T: ?Sized>: FragileMutContainer<T> + ContainerNew<T>;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`BoxLike`] kind is a [`FragileBoxLike`] kind, with the same containers.
+impl<K: BoxLike> FragileBoxLike for K {
+    type Container<T: ?Sized> = <K as BoxLike>::Container<T>;
+
+    const IS_FRAGILE: bool = false;
 }
 
 /// A [container kind trait](self) based on how `Rc<T>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`FragileRcLike`].
 pub trait RcLike {
-    /// An `Rc<T>`-like container type
-    type Container<T: ?Sized>: Container<T> + Clone;
+    /// An `Rc<T>`-like container type.
+    type Container<T: ?Sized>: Container<T> + ContainerNew<T> + Clone;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
 }
 
 /// A [container kind trait](self) based on how `Rc<T>` acts as a container for `T`.
@@ -112,23 +346,93 @@ pub trait RcLike {
 pub trait FragileRcLike {
     /// An `Rc<T>`-like container type, but permitted to be
     /// [fragile](crate#fragility-potential-panics-or-deadlocks).
-    type Container<T: ?Sized>: FragileContainer<T> + Clone;
+    type Container<T: ?Sized>: FragileContainer<T> + ContainerNew<T> + Clone;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`RcLike`] kind is a [`FragileRcLike`] kind, with the same containers.
+impl<K: RcLike> FragileRcLike for K {
+    type Container<T: ?Sized> = <K as RcLike>::Container<T>;
+
+    const IS_FRAGILE: bool = false;
 }
 
 /// A [container kind trait](self) based on how `Rc<RefCell<T>>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`FragileRcLike`].
 pub trait RcRefCellLike {
-    /// An `Rc<RefCell<T>>`-like container type
-    type Container<T: ?Sized>: FragileMutContainer<T> + Clone;
+    /// An `Rc<RefCell<T>>`-like container type.
+    type Container<T: ?Sized>: FragileMutContainer<T> + ContainerNew<T> + Clone;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
 }
 
 /// A [container kind trait](self) based on how `Arc<T>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`FragileArcLike`].
 pub trait ArcLike {
-    /// An `Arc<T>`-like container type
-    type Container<T: ?Sized + Send + Sync>: Container<T> + Clone + Send + Sync;
+    /// An `Arc<T>`-like container type.
+    type Container<T: ?Sized + Send + Sync>: Container<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send + Sync>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Send + Sync + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
 }
 
 /// A [container kind trait](self) based on how `Arc<T>` acts as a container for `T`.
@@ -138,27 +442,124 @@ pub trait ArcLike {
 pub trait FragileArcLike {
     /// An `Arc<T>`-like container type, but permitted to be
     /// [fragile](crate#fragility-potential-panics-or-deadlocks).
-    type Container<T: ?Sized + Send + Sync>: FragileContainer<T> + Clone + Send + Sync;
+    type Container<T: ?Sized + Send + Sync>:
+        FragileContainer<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send + Sync>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Send + Sync + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`ArcLike`] kind is a [`FragileArcLike`] kind, with the same containers.
+impl<K: ArcLike> FragileArcLike for K {
+    type Container<T: ?Sized + Send + Sync> = <K as ArcLike>::Container<T>;
+
+    const IS_FRAGILE: bool = false;
 }
 
 /// A [container kind trait](self) based on how `Arc<RwLock<T>>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`FragileArcLike`].
 pub trait ArcRwLockLike {
-    /// An `Arc<RwLock<T>>`-like container type
-    type Container<T: ?Sized + Send + Sync>: FragileMutContainer<T> + Clone + Send + Sync;
+    /// An `Arc<RwLock<T>>`-like container type.
+    type Container<T: ?Sized + Send + Sync>:
+        FragileMutContainer<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send + Sync>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Send + Sync + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
 }
 
 /// A [container kind trait](self) based on how `Arc<Mutex<T>>` acts as a container for `T`.
 ///
 /// Has strictly looser requirements than [`ArcRwLockLike`] and [`FragileArcLike`].
 pub trait ArcMutexLike {
-    /// An `Arc<Mutex<T>>`-like container type
-    type Container<T: ?Sized + Send>: FragileMutContainer<T> + Clone + Send + Sync;
+    /// An `Arc<Mutex<T>>`-like container type.
+    type Container<T: ?Sized + Send>:
+        FragileMutContainer<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Send + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// Every [`ArcMutexLike`] kind is an [`ArcRwLockLike`] kind, with the same containers.
+impl<K: ArcMutexLike> ArcRwLockLike for K {
+    type Container<T: ?Sized + Send + Sync> = <K as ArcMutexLike>::Container<T>;
+
+    const IS_FRAGILE: bool = <K as ArcMutexLike>::IS_FRAGILE;
 }
 
 /// A [container kind trait](self) based on how [`CheckedRcRefCell<T>`] acts as a container for `T`.
 ///
+/// Thread-safe kinds, such as [`ArcThreadCheckedMutexKind`], also implement this trait, as their
+/// containers meet its requirements. Generic code which needs its containers to be `Send + Sync`
+/// should use the thread-safe counterpart of this trait, [`ArcThreadCheckedMutexLike`], instead.
+///
+#[cfg_attr(
+    feature = "thread-checked-lock",
+    doc = "[`ArcThreadCheckedMutexKind`]: ArcThreadCheckedMutexKind",
+)]
+#[cfg_attr(
+    not(feature = "thread-checked-lock"),
+    doc = "[`ArcThreadCheckedMutexKind`]: \
+    https://docs.rs/generic-container/0/generic_container/kinds/\
+    struct.ArcThreadCheckedMutexKind.html",
+)]
 #[cfg_attr(
     feature = "alloc",
     doc = "[`CheckedRcRefCell<T>`]: crate::CheckedRcRefCell",
@@ -169,7 +570,7 @@ pub trait ArcMutexLike {
     https://docs.rs/generic-container/0/generic_container/struct.CheckedRcRefCell.html",
 )]
 pub trait CheckedRcRefCellLike {
-    /// A [`CheckedRcRefCell<T>`]-like container type
+    /// A [`CheckedRcRefCell<T>`]-like container type.
     ///
     #[cfg_attr(
         feature = "alloc",
@@ -180,12 +581,25 @@ pub trait CheckedRcRefCellLike {
         doc = "[`CheckedRcRefCell<T>`]: \
         https://docs.rs/generic-container/0/generic_container/struct.CheckedRcRefCell.html",
     )]
-    type Container<T: ?Sized>: TryMutContainer<T> + Clone;
+    type Container<T: ?Sized>: TryMutContainer<T> + ContainerNew<T> + Clone;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `false` by default.
+    const IS_FRAGILE: bool = false;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
 }
 
 /// A [container kind trait](self) based on how <code>Arc<[ThreadCheckedMutex]\<T\>></code> acts as
 /// a container for `T`.
 ///
+/// This is the thread-safe counterpart of [`CheckedRcRefCellLike`], for fallible but not
+/// [fragile](crate#fragility-potential-panics-or-deadlocks) containers which are `Send + Sync`.
+///
 #[cfg_attr(
     feature = "thread-checked-lock",
     doc = "[ThreadCheckedMutex]: thread_checked_lock::ThreadCheckedMutex",
@@ -196,7 +610,7 @@ pub trait CheckedRcRefCellLike {
     https://docs.rs/thread-checked-lock/0/thread_checked_lock/struct.ThreadCheckedMutex.html",
 )]
 pub trait ArcThreadCheckedMutexLike {
-    /// An <code>Arc<[ThreadCheckedMutex]\<T\>></code>-like container type
+    /// An <code>Arc<[ThreadCheckedMutex]\<T\>></code>-like container type.
     ///
     #[cfg_attr(
         feature = "thread-checked-lock",
@@ -207,7 +621,116 @@ pub trait ArcThreadCheckedMutexLike {
         doc = "[ThreadCheckedMutex]: \
         https://docs.rs/thread-checked-lock/0/thread_checked_lock/struct.ThreadCheckedMutex.html",
     )]
-    type Container<T: ?Sized + Send>: TryMutContainer<T> + Clone + Send + Sync;
+    type Container<T: ?Sized + Send>: TryMutContainer<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `false` by default.
+    const IS_FRAGILE: bool = false;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+}
+
+/// A [container kind trait](self) based on how `CowShared<Rc<T>>` acts as a clone-on-write
+/// container for `T`.
+///
+/// Mutable access requires `T: Clone`, as the inner `T` is cloned before being mutated if the
+/// container is shared.
+pub trait RcCowLike {
+    /// A `CowShared<Rc<T>>`-like container type.
+    type Container<T: Clone>: MutContainer<T> + ContainerNew<T> + Clone;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Clone>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Clone + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// A [container kind trait](self) based on how `CowShared<Arc<T>>` acts as a clone-on-write
+/// container for `T`.
+///
+/// Has strictly looser requirements than [`RcCowLike`].
+pub trait ArcCowLike {
+    /// A `CowShared<Arc<T>>`-like container type.
+    type Container<T: Clone + Send + Sync>:
+        MutContainer<T> + ContainerNew<T> + Clone + Send + Sync;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Clone + Send + Sync>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as ContainerNew<T>>::new_container(value)
+    }
+
+    /// Wrap and box `value` as `dyn DynContainer<T>`; see [wrapping values](self#wrapping-values).
+    #[cfg(any(feature = "alloc", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn new_dyn<T: Clone + Send + Sync + 'static>(value: T) -> Box<dyn DynContainer<T>>
+    where
+        Self::Container<T>: 'static,
+    {
+        <Self::Container<T> as DynContainer<T>>::boxed(Self::wrap(value))
+    }
+}
+
+/// A [container kind trait](self) based on how an `Arc` around an async mutex (such as
+/// `Arc<tokio::sync::Mutex<T>>` or `Arc<async_lock::Mutex<T>>`) acts as an [async container]
+/// for `T`.
+///
+/// [async container]: crate::async_container
+pub trait AsyncArcMutexLike {
+    /// An async `Arc<Mutex<T>>`-like container type.
+    type Container<T: ?Sized + Send>:
+        AsyncMutContainer<T> + AsyncContainerNew<T> + Clone + Send + Sync;
+
+    /// Whether the containers of this kind are [fragile](self#fragility); `true` by default.
+    const IS_FRAGILE: bool = true;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap<T: Send>(value: T) -> Self::Container<T> {
+        <Self::Container<T> as AsyncContainerNew<T>>::new_container(value)
+    }
+}
+
+/// A [container kind trait](self) based on how `Arc<AtomicU64>` acts as an [atomic container]
+/// for `u64`.
+///
+/// Atomics are not generic over the type they contain, so unlike the other kind traits, this
+/// trait's container is only a container for `u64`.
+///
+/// [atomic container]: crate::atomic_container
+#[cfg(target_has_atomic = "64")]
+pub trait AtomicU64Like {
+    /// An `Arc<AtomicU64>`-like container type.
+    type Container: AtomicContainerNew<u64> + Clone + Send + Sync;
+
+    /// Create a new container that owns `value`; see [wrapping values](self#wrapping-values).
+    #[inline]
+    #[must_use]
+    fn wrap(value: u64) -> Self::Container {
+        <Self::Container as AtomicContainerNew<u64>>::new_container(value)
+    }
 }
 
 // ================================
@@ -216,88 +739,59 @@ pub trait ArcThreadCheckedMutexLike {
 
 /// The [container kind](crate::kinds) corresponding to `T` as a container for itself.
 #[cfg_attr(docsrs, doc(cfg(feature = "kinds")))]
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TKind;
 
 impl TLike for TKind {
     type Container<T> = T;
 }
 
-impl FragileTLike for TKind {
-    type Container<T> = T;
-}
-
 #[cfg(any(feature = "alloc", doc))]
 mod alloc_kinds {
     use core::cell::RefCell;
     use alloc::{boxed::Box, rc::Rc, sync::Arc};
 
-    use crate::impls::CheckedRcRefCell;
+    use crate::impls::{CheckedRcRefCell, CowShared};
     use super::{
-        ArcLike, BoxLike, CheckedRcRefCellLike,
-        FragileArcLike, FragileBoxLike, FragileTLike, FragileRcLike,
-        RcLike, RcRefCellLike, TLike,
+        ArcCowLike, ArcLike, BoxLike, CheckedRcRefCellLike, FragileRcLike, RcCowLike, RcLike,
+        RcRefCellLike,
     };
 
 
     /// The [container kind](crate::kinds) corresponding to `Box<T>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct BoxKind;
 
     impl BoxLike for BoxKind {
         type Container<T: ?Sized> = Box<T>;
     }
 
-    impl FragileBoxLike for BoxKind {
-        type Container<T: ?Sized> = Box<T>;
-    }
-
-    impl TLike for BoxKind {
-        type Container<T> = Box<T>;
-    }
-
-    impl FragileTLike for BoxKind {
-        type Container<T> = Box<T>;
-    }
-
     /// The [container kind](crate::kinds) corresponding to `Rc<T>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct RcKind;
 
     impl RcLike for RcKind {
         type Container<T: ?Sized> = Rc<T>;
     }
 
-    impl FragileRcLike for RcKind {
-        type Container<T: ?Sized> = Rc<T>;
-    }
-
     /// The [container kind](crate::kinds) corresponding to `Arc<T>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ArcKind;
 
     impl ArcLike for ArcKind {
         type Container<T: ?Sized + Send + Sync> = Arc<T>;
     }
 
-    impl FragileArcLike for ArcKind {
-        type Container<T: ?Sized + Send + Sync> = Arc<T>;
-    }
-
     impl RcLike for ArcKind {
         type Container<T: ?Sized> = Arc<T>;
     }
 
-    impl FragileRcLike for ArcKind {
-        type Container<T: ?Sized> = Arc<T>;
-    }
-
     /// The [container kind](crate::kinds) corresponding to `Rc<RefCell<T>>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct RcRefCellKind;
 
     impl RcRefCellLike for RcRefCellKind {
@@ -311,28 +805,123 @@ mod alloc_kinds {
     /// The [container kind](crate::kinds) corresponding to [`CheckedRcRefCell<T>`] as a container
     /// for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct CheckedRcRefCellKind;
 
     impl CheckedRcRefCellLike for CheckedRcRefCellKind {
         type Container<T: ?Sized> = CheckedRcRefCell<T>;
     }
+
+    /// The [container kind](crate::kinds) corresponding to [`CowShared<Rc<T>>`] as a
+    /// clone-on-write container for `T`.
+    ///
+    /// [`CowShared<Rc<T>>`]: CowShared
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RcCowKind;
+
+    impl RcCowLike for RcCowKind {
+        type Container<T: Clone> = CowShared<Rc<T>>;
+    }
+
+    /// The [container kind](crate::kinds) corresponding to [`CowShared<Arc<T>>`] as a
+    /// clone-on-write container for `T`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::FragileMutContainer as _;
+    /// use generic_container::kinds::{ArcCowKind, ArcCowLike};
+    ///
+    /// fn edited_copy<K: ArcCowLike>(original: &K::Container<String>) -> K::Container<String> {
+    ///     let mut copy = original.clone();
+    ///     copy.get_mut().push_str(", edited");
+    ///     copy
+    /// }
+    ///
+    /// let original = ArcCowKind::wrap("draft".to_owned());
+    /// let copy = edited_copy::<ArcCowKind>(&original);
+    /// assert_eq!(*original.0, "draft");
+    /// assert_eq!(*copy.0, "draft, edited");
+    /// ```
+    ///
+    /// [`CowShared<Arc<T>>`]: CowShared
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ArcCowKind;
+
+    impl ArcCowLike for ArcCowKind {
+        type Container<T: Clone + Send + Sync> = CowShared<Arc<T>>;
+    }
+
+    impl RcCowLike for ArcCowKind {
+        type Container<T: Clone> = CowShared<Arc<T>>;
+    }
 }
 
 #[cfg(any(feature = "alloc", doc))]
-pub use self::alloc_kinds::{ArcKind, BoxKind, CheckedRcRefCellKind, RcKind, RcRefCellKind};
+pub use self::alloc_kinds::{
+    ArcCowKind, ArcKind, BoxKind, CheckedRcRefCellKind, RcCowKind, RcKind, RcRefCellKind,
+};
 
 #[cfg(any(feature = "std", doc))]
 mod std_kinds {
     use alloc::sync::Arc;
     use std::sync::{Mutex, RwLock};
 
-    use super::{ArcMutexLike, ArcRwLockLike, FragileArcLike};
+    use crate::impls::{CheckedArcMutex, CondvarContainer};
+    use super::{
+        ArcMutexLike, ArcRwLockLike, ArcThreadCheckedMutexLike, CheckedRcRefCellLike,
+        FragileArcLike, FragileTLike,
+    };
+
+
+    /// The [container kind](crate::kinds) corresponding to `Mutex<T>` as a container for `T`.
+    ///
+    /// A `Mutex<T>` is not `Clone`, so its containers have single ownership, like `Box<T>`. This
+    /// is useful for a `Mutex` which is uniquely owned by some other shared value. Since a
+    /// `Mutex<T>` is itself unsized if `T` is unsized, only [`FragileTLike`] is implemented.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use generic_container::FragileContainer;
+    /// use generic_container::kinds::{FragileTLike, MutexKind};
+    ///
+    /// struct Shared<K: FragileTLike> {
+    ///     counter: K::Container<u32>,
+    /// }
+    ///
+    /// let shared = Arc::new(Shared::<MutexKind> {
+    ///     counter: MutexKind::wrap(1),
+    /// });
+    /// *FragileContainer::<u32>::get_ref(&shared.counter) += 1;
+    /// assert_eq!(*FragileContainer::<u32>::get_ref(&shared.counter), 2);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MutexKind;
+
+    impl FragileTLike for MutexKind {
+        type Container<T> = Mutex<T>;
+    }
+
+    /// The [container kind](crate::kinds) corresponding to `RwLock<T>` as a container for `T`.
+    ///
+    /// An `RwLock<T>` is not `Clone`, so its containers have single ownership, like `Box<T>`. This
+    /// is useful for an `RwLock` which is uniquely owned by some other shared value. Since an
+    /// `RwLock<T>` is itself unsized if `T` is unsized, only [`FragileTLike`] is implemented.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RwLockKind;
+
+    impl FragileTLike for RwLockKind {
+        type Container<T> = RwLock<T>;
+    }
 
 
     /// The [container kind](crate::kinds) corresponding to `Arc<RwLock<T>>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ArcRwLockKind;
 
     impl ArcRwLockLike for ArcRwLockKind {
@@ -345,24 +934,95 @@ mod std_kinds {
 
     /// The [container kind](crate::kinds) corresponding to `Arc<Mutex<T>>` as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ArcMutexKind;
 
     impl ArcMutexLike for ArcMutexKind {
         type Container<T: ?Sized + Send> = Arc<Mutex<T>>;
     }
 
-    impl ArcRwLockLike for ArcMutexKind {
+    impl FragileArcLike for ArcMutexKind {
         type Container<T: ?Sized + Send + Sync> = Arc<Mutex<T>>;
     }
 
-    impl FragileArcLike for ArcMutexKind {
-        type Container<T: ?Sized + Send + Sync> = Arc<Mutex<T>>;
+    /// The [container kind](crate::kinds) corresponding to [`CondvarContainer<T>`] as a container
+    /// for `T`.
+    ///
+    /// Its containers act like those of [`ArcMutexKind`], and additionally support waiting for the
+    /// inner `T` to change with [`CondvarContainer::wait_while`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::thread;
+    /// use generic_container::FragileMutContainer;
+    /// use generic_container::kinds::{ArcMutexLike, CondvarKind};
+    ///
+    /// let ready = CondvarKind::wrap(false);
+    /// let mut setter = ready.clone();
+    ///
+    /// let handle = thread::spawn(move || {
+    ///     *FragileMutContainer::<bool>::get_mut(&mut setter) = true;
+    ///     setter.notify_all();
+    /// });
+    ///
+    /// assert!(*ready.wait_while(|ready| !ready));
+    /// handle.join().unwrap();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct CondvarKind;
+
+    impl ArcMutexLike for CondvarKind {
+        type Container<T: ?Sized + Send> = CondvarContainer<T>;
+    }
+
+    /// The [container kind](crate::kinds) corresponding to [`CheckedArcMutex<T>`] as a container
+    /// for `T`.
+    ///
+    /// This is the non-blocking counterpart of [`ArcMutexKind`]: its containers use
+    /// [`Mutex::try_lock`], so they never block and are not
+    /// [fragile](crate#fragility-potential-panics-or-deadlocks). Instead, [`try_get_ref`] and
+    /// [`try_get_mut`] fail with [`ErasedTryLockError::WouldBlock`] whenever the mutex is already
+    /// held, including by the current thread.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generic_container::{ErasedTryLockError, FragileTryContainer};
+    /// use generic_container::kinds::{ArcThreadCheckedMutexLike, CheckedArcMutexKind};
+    ///
+    /// fn shared_counter<K: ArcThreadCheckedMutexLike>() -> K::Container<u32> {
+    ///     K::wrap(0)
+    /// }
+    ///
+    /// let counter = shared_counter::<CheckedArcMutexKind>();
+    /// let guard = FragileTryContainer::<u32>::try_get_ref(&counter).unwrap();
+    /// assert_eq!(
+    ///     FragileTryContainer::<u32>::try_get_ref(&counter).err(),
+    ///     Some(ErasedTryLockError::WouldBlock),
+    /// );
+    /// drop(guard);
+    /// ```
+    ///
+    /// [`try_get_ref`]: crate::FragileTryContainer::try_get_ref
+    /// [`try_get_mut`]: crate::FragileTryMutContainer::try_get_mut
+    /// [`ErasedTryLockError::WouldBlock`]: crate::ErasedTryLockError::WouldBlock
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct CheckedArcMutexKind;
+
+    impl ArcThreadCheckedMutexLike for CheckedArcMutexKind {
+        type Container<T: ?Sized + Send> = CheckedArcMutex<T>;
+    }
+
+    impl CheckedRcRefCellLike for CheckedArcMutexKind {
+        type Container<T: ?Sized> = CheckedArcMutex<T>;
     }
 }
 
 #[cfg(any(feature = "std", doc))]
-pub use self::std_kinds::{ArcMutexKind, ArcRwLockKind};
+pub use self::std_kinds::{
+    ArcMutexKind, ArcRwLockKind, CheckedArcMutexKind, CondvarKind, MutexKind, RwLockKind,
+};
 
 #[cfg(feature = "thread-checked-lock")]
 mod thread_checked_lock_kinds {
@@ -376,7 +1036,7 @@ mod thread_checked_lock_kinds {
     /// The [container kind](crate::kinds) corresponding to
     /// <code>[Arc]<[ThreadCheckedMutex]\<T\>></code> as a container for `T`.
     #[cfg_attr(docsrs, doc(cfg(all(feature = "thread-checked-lock", feature = "kinds"))))]
-    #[derive(Default, Debug, Clone, Copy)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ArcThreadCheckedMutexKind;
 
     impl ArcThreadCheckedMutexLike for ArcThreadCheckedMutexKind {
@@ -390,3 +1050,136 @@ mod thread_checked_lock_kinds {
 
 #[cfg(feature = "thread-checked-lock")]
 pub use self::thread_checked_lock_kinds::ArcThreadCheckedMutexKind;
+
+#[cfg(all(any(feature = "alloc", doc), target_has_atomic = "64"))]
+mod atomic_kinds {
+    use alloc::sync::Arc;
+    use core::sync::atomic::AtomicU64;
+
+    use super::AtomicU64Like;
+
+
+    /// The [container kind](crate::kinds) corresponding to `Arc<AtomicU64>` as an
+    /// [atomic container](crate::atomic_container) for `u64`.
+    ///
+    /// Reading and writing the `u64` never locks, which makes this kind suitable for shared
+    /// counters and flags.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    /// use generic_container::AtomicContainer as _;
+    /// use generic_container::kinds::{ArcAtomicU64Kind, AtomicU64Like};
+    ///
+    /// fn store_through_clone<K: AtomicU64Like>() -> u64 {
+    ///     let counter = K::wrap(0);
+    ///     let clone = counter.clone();
+    ///     clone.store(2, Ordering::Relaxed);
+    ///     counter.load(Ordering::Relaxed)
+    /// }
+    ///
+    /// assert_eq!(store_through_clone::<ArcAtomicU64Kind>(), 2);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ArcAtomicU64Kind;
+
+    impl AtomicU64Like for ArcAtomicU64Kind {
+        type Container = Arc<AtomicU64>;
+    }
+}
+
+#[cfg(all(any(feature = "alloc", doc), target_has_atomic = "64"))]
+pub use self::atomic_kinds::ArcAtomicU64Kind;
+
+#[cfg(feature = "tokio")]
+mod tokio_kinds {
+    use alloc::sync::Arc;
+
+    use tokio::sync::Mutex;
+
+    use super::AsyncArcMutexLike;
+
+
+    /// The [container kind](crate::kinds) corresponding to `Arc<tokio::sync::Mutex<T>>` as an
+    /// [async container](crate::async_container) for `T`.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "tokio", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AsyncArcMutexKind;
+
+    impl AsyncArcMutexLike for AsyncArcMutexKind {
+        type Container<T: ?Sized + Send> = Arc<Mutex<T>>;
+    }
+}
+
+#[cfg(feature = "tokio")]
+pub use self::tokio_kinds::AsyncArcMutexKind;
+
+#[cfg(feature = "async-lock")]
+mod async_lock_kinds {
+    use alloc::sync::Arc;
+
+    use async_lock::Mutex;
+
+    use super::AsyncArcMutexLike;
+
+
+    /// The [container kind](crate::kinds) corresponding to `Arc<async_lock::Mutex<T>>` as an
+    /// [async container](crate::async_container) for `T`.
+    ///
+    /// Unlike [`AsyncArcMutexKind`], this kind does not depend on `tokio`, and its containers
+    /// may be used with any async executor.
+    ///
+    #[cfg_attr(
+        feature = "tokio",
+        doc = "[`AsyncArcMutexKind`]: super::AsyncArcMutexKind",
+    )]
+    #[cfg_attr(
+        not(feature = "tokio"),
+        doc = "[`AsyncArcMutexKind`]: \
+        https://docs.rs/generic-container/0/generic_container/kinds/struct.AsyncArcMutexKind.html",
+    )]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "async-lock", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ArcAsyncMutexKind;
+
+    impl AsyncArcMutexLike for ArcAsyncMutexKind {
+        type Container<T: ?Sized + Send> = Arc<Mutex<T>>;
+    }
+}
+
+#[cfg(feature = "async-lock")]
+pub use self::async_lock_kinds::ArcAsyncMutexKind;
+
+#[cfg(feature = "parking-lot")]
+mod parking_lot_kinds {
+    use alloc::sync::Arc;
+
+    use parking_lot::ReentrantMutex;
+
+    use super::{ArcLike, RcLike};
+
+
+    /// The [container kind](crate::kinds) corresponding to
+    /// `Arc<parking_lot::ReentrantMutex<T>>` as a container for `T`.
+    ///
+    /// Only immutable access is provided, but the lock may be acquired recursively by a single
+    /// thread, so the containers are not [fragile](crate#fragility-potential-panics-or-deadlocks).
+    /// Unlike [`ArcKind`], its containers are `Sync` even when `T` is only `Send`.
+    ///
+    /// [`ArcKind`]: super::ArcKind
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "parking-lot", feature = "kinds"))))]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ArcReentrantMutexKind;
+
+    impl ArcLike for ArcReentrantMutexKind {
+        type Container<T: ?Sized + Send + Sync> = Arc<ReentrantMutex<T>>;
+    }
+
+    impl RcLike for ArcReentrantMutexKind {
+        type Container<T: ?Sized> = Arc<ReentrantMutex<T>>;
+    }
+}
+
+#[cfg(feature = "parking-lot")]
+pub use self::parking_lot_kinds::ArcReentrantMutexKind;
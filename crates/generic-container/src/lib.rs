@@ -15,6 +15,8 @@
 //! [`GenericContainer<T, C>`]: GenericContainer
 //! [`TryContainer`]: TryContainer
 //! [`TryMutContainer<T>`]: TryMutContainer
+//! [`ContainerNew<T>`]: ContainerNew
+//! [`AsyncMutContainer<T>`]: AsyncMutContainer
 //!
 // File links are not supported by rustdoc
 //! [LICENSE-APACHE]: https://github.com/robofinch/generic-container/blob/main/LICENSE-APACHE
@@ -40,8 +42,26 @@ extern crate std;
 
 
 mod container_traits;
+#[cfg(any(feature = "alloc", doc))]
+mod container_iter;
+#[cfg(any(feature = "alloc", doc))]
+mod dyn_container;
+pub mod async_container;
+pub mod atomic_container;
+pub mod build;
 mod impls;
 mod generic_container;
+pub mod upcast;
+pub mod assertions;
+mod contained;
+mod snapshot;
+mod container_ext;
+mod slice_container_ext;
+mod poison;
+#[cfg(any(feature = "std", doc))]
+mod map_container_ext;
+#[cfg(feature = "serde")]
+mod container_serde;
 #[cfg(any(feature = "kinds", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "kinds")))]
 pub mod kinds;
@@ -53,19 +73,72 @@ use dupe as _;
 
 
 pub use self::generic_container::GenericContainer;
+pub use self::contained::Contained;
+pub use self::snapshot::SnapshotContainer;
+pub use self::container_ext::ContainerExt;
+pub use self::impls::{AssumeNonFragile, IntoInnerOrClone};
+pub use self::poison::MaybePoisonError;
+pub use self::slice_container_ext::SliceContainerExt;
 pub use self::container_traits::{
     // The core eight
     FragileTryContainer,    TryContainer,    FragileContainer,    Container,
     FragileTryMutContainer, TryMutContainer, FragileMutContainer, MutContainer,
 
+    // Construction
+    ContainerNew, TryContainerNew, DefaultContainer,
+
+    // Raw pointers and weak handles
+    ContainerPointer, WeakContainer,
+
+    // Projection
+    ProjectableContainer,
+
+    // Ownership and sharing
+    StrictContainer, TryCloneContainer,
+
     // Non-nightly "trait aliases"
     BaseContainer, BaseMutContainer,
+
+    // Errors
+    ContainerError,
 };
+pub use self::async_container::{AsyncContainer, AsyncContainerNew, AsyncMutContainer};
+pub use self::atomic_container::{AtomicContainer, AtomicContainerNew};
+
+#[cfg(any(feature = "alloc", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::container_iter::ContainerIter;
+
+#[cfg(any(feature = "alloc", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::container_traits::ContainerFromBox;
+
+#[cfg(any(feature = "alloc", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::dyn_container::DynContainer;
 
 #[cfg(any(feature = "alloc", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use self::impls::CheckedRcRefCell;
+pub use self::impls::{CheckedRcRefCell, CowShared};
+
+#[cfg(any(feature = "std", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use self::impls::{
+    CheckedArcMutex, CondvarContainer, ErasedTryLockError, MappedGuard, PoisonTolerant,
+};
+
+#[cfg(any(feature = "std", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use self::map_container_ext::MapContainerExt;
 
 #[cfg(feature = "thread-checked-lock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-checked-lock")))]
 pub use self::impls::ErasedLockError;
+
+#[cfg(feature = "blocking-unwrap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking-unwrap")))]
+pub use self::impls::BlockingUnwrap;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use self::container_serde::ContainerSerde;
@@ -0,0 +1,84 @@
+use core::{borrow::Borrow, hash::{BuildHasher, Hash}};
+use std::collections::HashMap;
+
+use crate::container_traits::FragileMutContainer;
+
+
+/// Extension trait for keyed access to a container whose inner `T` is a [`HashMap`].
+///
+/// Each method borrows the container only for the duration of a single lookup or insertion,
+/// which keeps lock scopes minimal for the common case of a shared map, such as
+/// `Arc<Mutex<HashMap<K, V>>>`.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Each method borrows the container once, with [`get_ref`] or [`get_mut`]. If the container is
+/// [fragile], then it must not already be borrowed by the current thread.
+///
+/// ## Examples
+/// ```
+/// use std::{collections::HashMap, sync::{Arc, Mutex}};
+/// use generic_container::MapContainerExt as _;
+///
+/// let mut scores = Arc::new(Mutex::new(HashMap::new()));
+///
+/// assert_eq!(scores.insert_entry("finch", 3), None);
+/// assert_eq!(scores.insert_entry("finch", 5), Some(3));
+///
+/// assert_eq!(scores.get_entry("finch"), Some(5));
+/// assert_eq!(scores.get_entry("robin"), None);
+/// ```
+///
+/// [`get_ref`]: crate::FragileContainer::get_ref
+/// [`get_mut`]: FragileMutContainer::get_mut
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait MapContainerExt<K, V, S>: FragileMutContainer<HashMap<K, V, S>> {
+    /// Borrow the inner map with [`get_ref`], and return a clone of the value corresponding to
+    /// `key`, if any.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_ref`]: crate::FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[must_use]
+    fn get_entry<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+        V: Clone;
+
+    /// Mutably borrow the inner map with [`get_mut`], and insert `value` for `key`, returning
+    /// the previous value for `key`, if any.
+    ///
+    /// See [`HashMap::insert`].
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_mut`]: FragileMutContainer::get_mut
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    fn insert_entry(&mut self, key: K, value: V) -> Option<V>;
+}
+
+impl<K, V, S, C> MapContainerExt<K, V, S> for C
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+    C: ?Sized + FragileMutContainer<HashMap<K, V, S>>,
+{
+    #[inline]
+    fn get_entry<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+        V: Clone,
+    {
+        self.get_ref().get(key).cloned()
+    }
+
+    #[inline]
+    fn insert_entry(&mut self, key: K, value: V) -> Option<V> {
+        self.get_mut().insert(key, value)
+    }
+}
@@ -0,0 +1,102 @@
+use core::convert::Infallible;
+use core::cell::{BorrowError, BorrowMutError};
+
+
+/// An error returned by a container's [`try_get_ref`] or [`try_get_mut`] which may have been
+/// caused by poison.
+///
+/// The container error types returned for poisonable locks, such as [`ErasedLockError`] and
+/// [`ErasedTryLockError`], erase the poison error's data, so poison cannot be ignored to recover
+/// the inner `T`. This trait allows code which is generic over a container to at least detect
+/// poison uniformly, for instance to panic on poison while handling other errors.
+///
+/// Implemented for each error type returned by this crate's containers. Error types of
+/// containers which cannot be poisoned, such as [`Infallible`] and [`BorrowError`], never report
+/// poison.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::{CheckedArcMutex, FragileTryContainer, MaybePoisonError};
+///
+/// fn try_len<C>(container: &C) -> Option<usize>
+/// where
+///     C: FragileTryContainer<Vec<u8>, RefError: MaybePoisonError>,
+/// {
+///     container
+///         .try_get_ref()
+///         .map_err(MaybePoisonError::panic_if_poison)
+///         .ok()
+///         .map(|list| list.len())
+/// }
+///
+/// let container = CheckedArcMutex(Arc::new(Mutex::new(vec![1, 2])));
+/// assert_eq!(try_len(&container), Some(2));
+///
+/// let guard = container.0.lock().unwrap();
+/// assert_eq!(try_len(&container), None);
+/// drop(guard);
+/// ```
+///
+/// [`try_get_ref`]: crate::FragileTryContainer::try_get_ref
+/// [`try_get_mut`]: crate::FragileTryMutContainer::try_get_mut
+#[cfg_attr(
+    feature = "thread-checked-lock",
+    doc = "[`ErasedLockError`]: crate::ErasedLockError",
+)]
+#[cfg_attr(
+    not(feature = "thread-checked-lock"),
+    doc = "[`ErasedLockError`]: \
+    https://docs.rs/generic-container/0/generic_container/enum.ErasedLockError.html",
+)]
+#[cfg_attr(
+    feature = "std",
+    doc = "[`ErasedTryLockError`]: crate::ErasedTryLockError",
+)]
+#[cfg_attr(
+    not(feature = "std"),
+    doc = "[`ErasedTryLockError`]: \
+    https://docs.rs/generic-container/0/generic_container/enum.ErasedTryLockError.html",
+)]
+pub trait MaybePoisonError: Sized {
+    /// Determines whether the error was caused by poison.
+    #[must_use]
+    fn is_poison(&self) -> bool;
+
+    /// Panics if the error was caused by poison, and otherwise returns the error unchanged.
+    ///
+    /// # Panics
+    /// Panics if [`is_poison`] returns `true`.
+    ///
+    /// [`is_poison`]: MaybePoisonError::is_poison
+    #[inline]
+    #[must_use]
+    fn panic_if_poison(self) -> Self {
+        assert!(!self.is_poison(), "container error was poison");
+        self
+    }
+}
+
+impl MaybePoisonError for Infallible {
+    /// Always returns `false`, as an `Infallible` error cannot exist.
+    #[inline]
+    fn is_poison(&self) -> bool {
+        false
+    }
+}
+
+impl MaybePoisonError for BorrowError {
+    /// Always returns `false`, as a `RefCell` cannot be poisoned.
+    #[inline]
+    fn is_poison(&self) -> bool {
+        false
+    }
+}
+
+impl MaybePoisonError for BorrowMutError {
+    /// Always returns `false`, as a `RefCell` cannot be poisoned.
+    #[inline]
+    fn is_poison(&self) -> bool {
+        false
+    }
+}
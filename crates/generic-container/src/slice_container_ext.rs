@@ -0,0 +1,81 @@
+use crate::container_traits::FragileContainer;
+
+
+/// Extension trait for length-aware access to a container whose inner `T` is a slice `[U]`,
+/// such as `Arc<[U]>` or `Box<[U]>`.
+///
+/// Such containers can be created from a `Vec<U>` with [`ContainerFromBox::from_boxed`] and
+/// `Vec::into_boxed_slice`, or with [`GenericContainer::from_vec`].
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Each method borrows the container once with [`get_ref`]. If the container is [fragile], then
+/// it must not already be borrowed by the current thread.
+///
+/// ## Examples
+/// ```
+/// use std::sync::Arc;
+/// use generic_container::{GenericContainer, SliceContainerExt as _};
+///
+/// let shared: GenericContainer<[u8], Arc<[u8]>> = GenericContainer::from_vec(vec![1, 2, 3]);
+/// let slice = shared.container_ref();
+///
+/// assert_eq!(slice.len(), 3);
+/// assert!(!slice.is_empty());
+/// assert_eq!(slice.get_cloned(1), Some(2));
+/// assert_eq!(slice.get_cloned(3), None);
+/// ```
+///
+/// [`ContainerFromBox::from_boxed`]: crate::ContainerFromBox::from_boxed
+/// [`GenericContainer::from_vec`]: crate::GenericContainer::from_vec
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait SliceContainerExt<U>: FragileContainer<[U]> {
+    /// Borrow the inner slice with [`get_ref`], and return its length.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[must_use]
+    fn len(&self) -> usize;
+
+    /// Borrow the inner slice with [`get_ref`], and return whether it is empty.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[must_use]
+    fn is_empty(&self) -> bool;
+
+    /// Borrow the inner slice with [`get_ref`], and return a clone of the element at `index`,
+    /// or `None` if `index` is out of bounds.
+    ///
+    /// # Panics and Deadlocks
+    /// May panic or deadlock if the container is [fragile] and its contract is broken.
+    ///
+    /// [`get_ref`]: FragileContainer::get_ref
+    /// [fragile]: crate#fragility-potential-panics-or-deadlocks
+    #[must_use]
+    fn get_cloned(&self, index: usize) -> Option<U> where U: Clone;
+}
+
+impl<U, C: ?Sized + FragileContainer<[U]>> SliceContainerExt<U> for C {
+    #[inline]
+    fn len(&self) -> usize {
+        self.get_ref().len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.get_ref().is_empty()
+    }
+
+    #[inline]
+    fn get_cloned(&self, index: usize) -> Option<U> where U: Clone {
+        self.get_ref().get(index).cloned()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::container_traits::{FragileContainer, FragileTryContainer};
+
+
+/// Extension trait for cloning the value inside a container, holding the borrow (such as a lock
+/// guard) only for the duration of the clone.
+///
+/// This standardizes the common `let value = container.lock().unwrap().clone();` idiom across
+/// every container: for lock-based containers like `Arc<Mutex<T>>` or `Rc<RefCell<T>>`, the lock
+/// or borrow is acquired, the inner `T` is cloned, and the lock or borrow is released before
+/// returning. For containers like `Box<T>`, `Arc<T>`, or `T` itself, the inner `T` is simply
+/// cloned.
+///
+/// # Fragility: Potential Panics or Deadlocks
+///
+/// Each method borrows the container once, with [`try_get_ref`] or [`get_ref`]. If the container
+/// is [fragile], then it must not already be borrowed by the current thread.
+///
+/// ## Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use generic_container::SnapshotContainer as _;
+///
+/// let container = Arc::new(Mutex::new(vec![1, 2, 3]));
+/// let snapshot: Vec<i32> = container.snapshot();
+///
+/// container.lock().unwrap().push(4);
+/// assert_eq!(snapshot, [1, 2, 3]);
+/// ```
+///
+/// [`try_get_ref`]: FragileTryContainer::try_get_ref
+/// [`get_ref`]: FragileContainer::get_ref
+/// [fragile]: crate#fragility-potential-panics-or-deadlocks
+pub trait SnapshotContainer<T: ?Sized>: FragileTryContainer<T> {
+    /// Borrow the container once, and return a clone of the inner `T`.
+    ///
+    /// # Errors
+    /// Errors if and only if [`try_get_ref`] errors.
+    ///
+    /// [`try_get_ref`]: FragileTryContainer::try_get_ref
+    fn try_snapshot(&self) -> Result<T, Self::RefError>
+    where
+        T: Clone;
+
+    /// Borrow the container once, and return a clone of the inner `T`.
+    fn snapshot(&self) -> T
+    where
+        T: Clone,
+        Self: FragileContainer<T>;
+}
+
+impl<T: ?Sized, C: ?Sized + FragileTryContainer<T>> SnapshotContainer<T> for C {
+    #[inline]
+    fn try_snapshot(&self) -> Result<T, Self::RefError>
+    where
+        T: Clone,
+    {
+        self.try_get_ref().map(|inner| (*inner).clone())
+    }
+
+    #[inline]
+    fn snapshot(&self) -> T
+    where
+        T: Clone,
+        Self: FragileContainer<T>,
+    {
+        (*self.get_ref()).clone()
+    }
+}
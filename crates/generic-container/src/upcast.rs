@@ -0,0 +1,136 @@
+//! # Upcasting Helpers
+//!
+//! Functions which view a container as an opaque implementor of one of the container traits.
+//!
+//! The container trait hierarchy is understood by the compiler (for instance, every
+//! [`MutContainer<T>`] is a [`Container<T>`]), but a function which returns a concrete container
+//! type cannot express "some `Container<T>`" without naming the type. These functions perform that
+//! conversion explicitly, which can avoid turbofish noise in generic adapters.
+//!
+//! Every function here is the identity function on the provided reference, and is zero-cost; only
+//! the static type of the reference changes.
+//!
+//! ## Examples
+//! ```
+//! use generic_container::{upcast, Container, FragileContainer as _};
+//!
+//! fn read_only(container: &Box<u32>) -> &impl Container<u32> {
+//!     upcast::as_container(container)
+//! }
+//!
+//! let container = Box::new(5);
+//! assert_eq!(*read_only(&container).get_ref(), 5);
+//! ```
+//!
+//! [`MutContainer<T>`]: MutContainer
+//! [`Container<T>`]: Container
+
+use crate::container_traits::{
+    Container, FragileContainer, FragileMutContainer, FragileTryContainer, FragileTryMutContainer,
+    MutContainer, TryContainer, TryMutContainer,
+};
+
+
+/// View a container as an opaque [`FragileTryContainer<T>`].
+///
+/// [`FragileTryContainer<T>`]: FragileTryContainer
+#[inline]
+#[must_use]
+pub fn as_fragile_try_container<T, C>(container: &C) -> &impl FragileTryContainer<T>
+where
+    T: ?Sized,
+    C: FragileTryContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`TryContainer<T>`].
+///
+/// [`TryContainer<T>`]: TryContainer
+#[inline]
+#[must_use]
+pub fn as_try_container<T, C>(container: &C) -> &impl TryContainer<T>
+where
+    T: ?Sized,
+    C: TryContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`FragileContainer<T>`].
+///
+/// [`FragileContainer<T>`]: FragileContainer
+#[inline]
+#[must_use]
+pub fn as_fragile_container<T, C>(container: &C) -> &impl FragileContainer<T>
+where
+    T: ?Sized,
+    C: FragileContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`Container<T>`].
+///
+/// [`Container<T>`]: Container
+#[inline]
+#[must_use]
+pub fn as_container<T, C>(container: &C) -> &impl Container<T>
+where
+    T: ?Sized,
+    C: Container<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`FragileTryMutContainer<T>`].
+///
+/// [`FragileTryMutContainer<T>`]: FragileTryMutContainer
+#[inline]
+#[must_use]
+pub fn as_fragile_try_mut_container<T, C>(container: &mut C) -> &mut impl FragileTryMutContainer<T>
+where
+    T: ?Sized,
+    C: FragileTryMutContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`TryMutContainer<T>`].
+///
+/// [`TryMutContainer<T>`]: TryMutContainer
+#[inline]
+#[must_use]
+pub fn as_try_mut_container<T, C>(container: &mut C) -> &mut impl TryMutContainer<T>
+where
+    T: ?Sized,
+    C: TryMutContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`FragileMutContainer<T>`].
+///
+/// [`FragileMutContainer<T>`]: FragileMutContainer
+#[inline]
+#[must_use]
+pub fn as_fragile_mut_container<T, C>(container: &mut C) -> &mut impl FragileMutContainer<T>
+where
+    T: ?Sized,
+    C: FragileMutContainer<T>,
+{
+    container
+}
+
+/// View a container as an opaque [`MutContainer<T>`].
+///
+/// [`MutContainer<T>`]: MutContainer
+#[inline]
+#[must_use]
+pub fn as_mut_container<T, C>(container: &mut C) -> &mut impl MutContainer<T>
+where
+    T: ?Sized,
+    C: MutContainer<T>,
+{
+    container
+}
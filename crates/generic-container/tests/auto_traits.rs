@@ -0,0 +1,188 @@
+//! Compile-time assertions pinning which of the provided containers and container kinds are
+//! `Send + Sync`, and which are `!Send`.
+//!
+//! Every assertion is evaluated during compilation; if this file compiles, the assertions hold.
+
+#![allow(
+    unused_crate_dependencies,
+    reason = "optional dependencies are only used through `generic_container`",
+)]
+
+#[cfg(feature = "alloc")]
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+#[cfg(feature = "std")]
+use std::{cell::Cell, sync::{Mutex, RwLock}};
+
+#[cfg(feature = "alloc")]
+use generic_container::CheckedRcRefCell;
+#[cfg(feature = "std")]
+use generic_container::{CheckedArcMutex, CondvarContainer, GenericContainer, PoisonTolerant};
+
+
+const fn assert_send_sync<T: ?Sized + Send + Sync>() {}
+
+/// Implemented twice for every `Send` type, so that naming `some_item` for a `Send` type is
+/// ambiguous and fails to compile.
+#[cfg(feature = "alloc")]
+trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+/// Fails to compile if the given type is `Send`.
+#[cfg(feature = "alloc")]
+macro_rules! assert_not_send {
+    ($ty:ty) => {
+        const _: fn() = || {
+            let _ = <$ty as crate::AmbiguousIfSend<_>>::some_item;
+        };
+    };
+}
+
+
+const _: () = {
+    assert_send_sync::<i32>();
+    assert_send_sync::<&i32>();
+    assert_send_sync::<&mut i32>();
+};
+
+#[cfg(feature = "alloc")]
+const _: () = {
+    assert_send_sync::<Box<i32>>();
+    assert_send_sync::<Arc<i32>>();
+};
+
+#[cfg(feature = "alloc")]
+assert_not_send!(Rc<i32>);
+#[cfg(feature = "alloc")]
+assert_not_send!(Rc<RefCell<i32>>);
+#[cfg(feature = "alloc")]
+assert_not_send!(CheckedRcRefCell<i32>);
+
+#[cfg(feature = "std")]
+const _: () = {
+    assert_send_sync::<Arc<Mutex<i32>>>();
+    assert_send_sync::<Arc<RwLock<i32>>>();
+    assert_send_sync::<CheckedArcMutex<i32>>();
+    assert_send_sync::<CondvarContainer<i32>>();
+    assert_send_sync::<PoisonTolerant<Arc<Mutex<i32>>>>();
+    assert_send_sync::<PoisonTolerant<Arc<RwLock<i32>>>>();
+    assert_send_sync::<GenericContainer<i32, Arc<Mutex<i32>>>>();
+
+    // A mutex only requires its contents to be `Send`.
+    assert_send_sync::<Arc<Mutex<Cell<i32>>>>();
+    assert_send_sync::<CheckedArcMutex<Cell<i32>>>();
+};
+
+#[cfg(feature = "thread-checked-lock")]
+const _: () = {
+    use thread_checked_lock::ThreadCheckedMutex;
+
+    assert_send_sync::<Arc<ThreadCheckedMutex<i32>>>();
+    assert_send_sync::<Arc<ThreadCheckedMutex<Cell<i32>>>>();
+};
+
+#[cfg(feature = "tokio")]
+const _: () = {
+    use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
+
+    assert_send_sync::<Arc<TokioMutex<i32>>>();
+    assert_send_sync::<Arc<TokioRwLock<i32>>>();
+};
+
+#[cfg(feature = "async-lock")]
+const _: () = {
+    use async_lock::{Mutex as AsyncMutex, RwLock as AsyncRwLock};
+
+    assert_send_sync::<Arc<AsyncMutex<i32>>>();
+    assert_send_sync::<Arc<AsyncRwLock<i32>>>();
+};
+
+#[cfg(feature = "parking-lot")]
+const _: () = {
+    use parking_lot::ReentrantMutex;
+
+    assert_send_sync::<Arc<ReentrantMutex<i32>>>();
+};
+
+
+#[cfg(feature = "kinds")]
+mod kinds {
+    use generic_container::kinds::{TKind, TLike};
+
+    use super::assert_send_sync;
+
+    const _: () = {
+        assert_send_sync::<<TKind as TLike>::Container<i32>>();
+    };
+
+    #[cfg(feature = "alloc")]
+    const _: () = {
+        use generic_container::kinds::{
+            ArcAtomicU64Kind, ArcKind, ArcLike, AtomicU64Like, BoxKind, BoxLike,
+        };
+
+        assert_send_sync::<<BoxKind as BoxLike>::Container<i32>>();
+        assert_send_sync::<<ArcKind as ArcLike>::Container<i32>>();
+        assert_send_sync::<<ArcAtomicU64Kind as AtomicU64Like>::Container>();
+    };
+
+    #[cfg(feature = "alloc")]
+    mod not_send {
+        use generic_container::kinds::{
+            CheckedRcRefCellKind, CheckedRcRefCellLike, RcKind, RcLike, RcRefCellKind,
+            RcRefCellLike,
+        };
+
+        assert_not_send!(<RcKind as RcLike>::Container<i32>);
+        assert_not_send!(<RcRefCellKind as RcRefCellLike>::Container<i32>);
+        assert_not_send!(<CheckedRcRefCellKind as CheckedRcRefCellLike>::Container<i32>);
+    }
+
+    #[cfg(feature = "std")]
+    const _: () = {
+        use generic_container::kinds::{
+            ArcMutexKind, ArcMutexLike, ArcRwLockKind, ArcRwLockLike, ArcThreadCheckedMutexLike,
+            CheckedArcMutexKind, CondvarKind,
+        };
+
+        assert_send_sync::<<ArcMutexKind as ArcMutexLike>::Container<i32>>();
+        assert_send_sync::<<ArcRwLockKind as ArcRwLockLike>::Container<i32>>();
+        assert_send_sync::<<CheckedArcMutexKind as ArcThreadCheckedMutexLike>::Container<i32>>();
+        assert_send_sync::<<CondvarKind as ArcMutexLike>::Container<i32>>();
+    };
+
+    #[cfg(feature = "thread-checked-lock")]
+    const _: () = {
+        use generic_container::kinds::{ArcThreadCheckedMutexKind, ArcThreadCheckedMutexLike};
+
+        assert_send_sync::<
+            <ArcThreadCheckedMutexKind as ArcThreadCheckedMutexLike>::Container<i32>,
+        >();
+    };
+
+    #[cfg(feature = "tokio")]
+    const _: () = {
+        use generic_container::kinds::{AsyncArcMutexKind, AsyncArcMutexLike};
+
+        assert_send_sync::<<AsyncArcMutexKind as AsyncArcMutexLike>::Container<i32>>();
+    };
+
+    #[cfg(feature = "async-lock")]
+    const _: () = {
+        use generic_container::kinds::{ArcAsyncMutexKind, AsyncArcMutexLike};
+
+        assert_send_sync::<<ArcAsyncMutexKind as AsyncArcMutexLike>::Container<i32>>();
+    };
+
+    #[cfg(feature = "parking-lot")]
+    const _: () = {
+        use generic_container::kinds::{ArcLike, ArcReentrantMutexKind};
+
+        assert_send_sync::<<ArcReentrantMutexKind as ArcLike>::Container<i32>>();
+    };
+}
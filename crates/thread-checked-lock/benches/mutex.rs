@@ -0,0 +1,125 @@
+//! Benchmarks comparing [`ThreadCheckedMutex`] against [`std::sync::Mutex`] and
+//! [`parking_lot::Mutex`].
+//!
+//! Run with `cargo bench -p thread-checked-lock`. To guard against regressions, save a baseline
+//! before a change with `cargo bench -p thread-checked-lock -- --save-baseline before`, and then
+//! compare against it with `cargo bench -p thread-checked-lock -- --baseline before`.
+
+#![allow(unused_crate_dependencies, reason = "`serde` is only an optional dependency")]
+#![expect(missing_docs, reason = "`criterion_group!` generates an undocumented function")]
+#![expect(clippy::unwrap_used, reason = "these are benchmarks")]
+#![expect(clippy::mutex_integer, reason = "the mutexes are what is being measured")]
+
+use std::{hint::black_box, iter, sync::Mutex, thread};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use parking_lot::Mutex as ParkingLotMutex;
+
+use thread_checked_lock::{HandlePoisonResult as _, ThreadCheckedMutex};
+
+
+/// Numbers of other `ThreadCheckedMutex`es held by the current thread while locking, which affect
+/// the cost of the thread-local registry of held mutexes.
+const HELD_LOCKS: [usize; 4] = [0, 1, 4, 8];
+/// Number of threads contending for a single mutex.
+const CONTENDING_THREADS: usize = 4;
+/// Number of times each contending thread locks the mutex, per iteration.
+const LOCKS_PER_THREAD: u32 = 1_000;
+
+
+/// A single lock and unlock, with no other locks held and no contention.
+fn uncontended(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("uncontended");
+
+    let std_mutex = Mutex::new(0_u64);
+    group.bench_function("std", |bencher| bencher.iter(|| {
+        *black_box(&std_mutex).lock().unwrap() += 1;
+    }));
+
+    let parking_lot_mutex = ParkingLotMutex::new(0_u64);
+    group.bench_function("parking_lot", |bencher| bencher.iter(|| {
+        *black_box(&parking_lot_mutex).lock() += 1;
+    }));
+
+    let checked_mutex = ThreadCheckedMutex::new(0_u64);
+    group.bench_function("thread_checked", |bencher| bencher.iter(|| {
+        *black_box(&checked_mutex).lock().panic_if_poison().unwrap() += 1;
+    }));
+    group.bench_function("thread_checked_unchecked", |bencher| bencher.iter(|| {
+        *black_box(&checked_mutex).lock_unchecked().unwrap() += 1;
+    }));
+
+    group.finish();
+}
+
+/// A single lock and unlock, while the current thread holds some number of other
+/// `ThreadCheckedMutex`es.
+fn with_held_locks(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("with_held_locks");
+
+    for held in HELD_LOCKS {
+        let others = iter::repeat_with(|| ThreadCheckedMutex::new(())).take(held).collect::<Vec<_>>();
+        let _guards = others
+            .iter()
+            .map(|other| other.lock().panic_if_poison().unwrap())
+            .collect::<Vec<_>>();
+
+        let mutex = ThreadCheckedMutex::new(0_u64);
+        let id = BenchmarkId::new("thread_checked", held);
+        group.bench_with_input(id, &mutex, |bencher, mutex| bencher.iter(|| {
+            *black_box(mutex).lock().panic_if_poison().unwrap() += 1;
+        }));
+    }
+
+    group.finish();
+}
+
+/// Several threads repeatedly locking the same mutex.
+fn contended(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("contended");
+
+    let std_mutex = Mutex::new(0_u64);
+    group.bench_function("std", |bencher| bencher.iter(|| {
+        thread::scope(|scope| {
+            for _ in 0..CONTENDING_THREADS {
+                scope.spawn(|| {
+                    for _ in 0..LOCKS_PER_THREAD {
+                        *black_box(&std_mutex).lock().unwrap() += 1;
+                    }
+                });
+            }
+        });
+    }));
+
+    let parking_lot_mutex = ParkingLotMutex::new(0_u64);
+    group.bench_function("parking_lot", |bencher| bencher.iter(|| {
+        thread::scope(|scope| {
+            for _ in 0..CONTENDING_THREADS {
+                scope.spawn(|| {
+                    for _ in 0..LOCKS_PER_THREAD {
+                        *black_box(&parking_lot_mutex).lock() += 1;
+                    }
+                });
+            }
+        });
+    }));
+
+    let checked_mutex = ThreadCheckedMutex::new(0_u64);
+    group.bench_function("thread_checked", |bencher| bencher.iter(|| {
+        thread::scope(|scope| {
+            for _ in 0..CONTENDING_THREADS {
+                scope.spawn(|| {
+                    for _ in 0..LOCKS_PER_THREAD {
+                        *black_box(&checked_mutex).lock().panic_if_poison().unwrap() += 1;
+                    }
+                });
+            }
+        });
+    }));
+
+    group.finish();
+}
+
+
+criterion_group!(benches, uncontended, with_held_locks, contended);
+criterion_main!(benches);
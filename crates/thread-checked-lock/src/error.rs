@@ -1,4 +1,5 @@
-use std::{convert::Infallible, error::Error, sync::PoisonError};
+use std::{convert::Infallible, error::Error, iter::Map};
+use std::sync::{PoisonError, TryLockError as StdTryLockError};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 
@@ -24,6 +25,7 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 ///
 ///
 /// [`parking_lot`]: https://docs.rs/parking_lot/
+/// [`ThreadCheckedMutex`]: crate::ThreadCheckedMutex
 pub trait HandlePoisonResult {
     /// A variation of the `Self` result type which cannot possibly be a poison error.
     type PoisonlessResult;
@@ -31,7 +33,7 @@ pub trait HandlePoisonResult {
     /// Silently converts any poison error into a successful result (see
     /// [`PoisonError::into_inner`]), and otherwise returns the result unchanged.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[must_use]
     fn ignore_poison(self) -> Self::PoisonlessResult;
 
@@ -40,10 +42,102 @@ pub trait HandlePoisonResult {
     /// # Panics
     /// Panics if the result is an [`Err`] that was caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     fn panic_if_poison(self) -> Self::PoisonlessResult;
 }
 
+impl<R: HandlePoisonResult> HandlePoisonResult for Option<R> {
+    type PoisonlessResult = Option<R::PoisonlessResult>;
+
+    /// Applies [`HandlePoisonResult::ignore_poison`] to the contained result, if any.
+    ///
+    /// [Read more about poison](HandlePoisonResult#about-poison).
+    #[inline]
+    fn ignore_poison(self) -> Self::PoisonlessResult {
+        self.map(R::ignore_poison)
+    }
+
+    /// Applies [`HandlePoisonResult::panic_if_poison`] to the contained result, if any.
+    ///
+    /// # Panics
+    /// Panics if the contained result is an [`Err`] that was caused by poison.
+    ///
+    /// [Read more about poison](HandlePoisonResult#about-poison).
+    #[inline]
+    fn panic_if_poison(self) -> Self::PoisonlessResult {
+        self.map(R::panic_if_poison)
+    }
+}
+
+/// Extension trait for collections or iterators of results, such as a `Vec<LockResult<T>>`,
+/// which applies the [`HandlePoisonResult`] methods to every result.
+///
+/// The results are handled lazily, as the returned iterator is consumed.
+///
+/// ## Examples
+/// ```
+/// use thread_checked_lock::{HandleAllPoison as _, ThreadCheckedMutex};
+///
+/// let mutexes = [ThreadCheckedMutex::new(1), ThreadCheckedMutex::new(2)];
+///
+/// let guards = mutexes
+///     .iter()
+///     .map(ThreadCheckedMutex::lock)
+///     .ignore_all_poison()
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(guards.iter().map(|guard| **guard).sum::<i32>(), 3);
+/// ```
+pub trait HandleAllPoison: IntoIterator<Item: HandlePoisonResult> + Sized {
+    /// Returns an iterator which applies [`HandlePoisonResult::ignore_poison`] to each result.
+    ///
+    /// [Read more about poison](HandlePoisonResult#about-poison).
+    fn ignore_all_poison(self) -> PoisonlessIter<Self::IntoIter>;
+
+    /// Returns an iterator which applies [`HandlePoisonResult::panic_if_poison`] to each result.
+    ///
+    /// # Panics
+    /// The returned iterator panics when it reaches an [`Err`] that was caused by poison.
+    ///
+    /// [Read more about poison](HandlePoisonResult#about-poison).
+    fn panic_if_any_poison(self) -> PoisonlessIter<Self::IntoIter>;
+}
+
+/// The iterator returned by the methods of [`HandleAllPoison`].
+pub type PoisonlessIter<I> = Map<
+    I,
+    fn(<I as Iterator>::Item) -> <<I as Iterator>::Item as HandlePoisonResult>::PoisonlessResult,
+>;
+
+impl<I> HandleAllPoison for I
+where
+    I: IntoIterator<Item: HandlePoisonResult>,
+{
+    #[inline]
+    fn ignore_all_poison(self) -> PoisonlessIter<Self::IntoIter> {
+        self.into_iter().map(HandlePoisonResult::ignore_poison)
+    }
+
+    #[inline]
+    fn panic_if_any_poison(self) -> PoisonlessIter<Self::IntoIter> {
+        self.into_iter().map(HandlePoisonResult::panic_if_poison)
+    }
+}
+
+/// Helper function to clone a poison error, which does not itself implement [`Clone`].
+#[inline]
+fn clone_poison<T: Clone>(poison: &PoisonError<T>) -> PoisonError<T> {
+    PoisonError::new(poison.get_ref().clone())
+}
+
+/// Helper function to drop the data of a poison error, leaving a `'static` poison error.
+#[inline]
+fn erase_poison<T>(poison: PoisonError<T>) -> PoisonError<()> {
+    drop(poison);
+    PoisonError::new(())
+}
+
 /// Helper function to coerce an uninhabited poison error into `!`.
 #[inline]
 fn prove_unreachable(poison: &PoisonError<Infallible>) -> ! {
@@ -65,7 +159,7 @@ impl<T> HandlePoisonResult for LockResult<T> {
     /// Silently converts any poison error into a successful result (see
     /// [`PoisonError::into_inner`]), and otherwise returns the result unchanged.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn ignore_poison(self) -> Self::PoisonlessResult {
         match self.map_err(LockError::ignore_poison) {
@@ -79,7 +173,7 @@ impl<T> HandlePoisonResult for LockResult<T> {
     /// # Panics
     /// Panics if the result is an [`Err`] that was caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn panic_if_poison(self) -> Self::PoisonlessResult {
         self.map_err(LockError::panic_if_poison)
@@ -88,22 +182,34 @@ impl<T> HandlePoisonResult for LockResult<T> {
 
 /// An error that may be returned by [`ThreadCheckedMutex::lock`].
 ///
+/// A [`TryLockError`] can be converted into a `LockError`, in which case the [`WouldBlock`]
+/// variant may also occur.
+///
 /// [`ThreadCheckedMutex::lock`]: super::mutex::ThreadCheckedMutex::lock
+/// [`WouldBlock`]: LockError::WouldBlock
 pub enum LockError<T> {
     /// Returned when a lock was acquired, but the lock was poisoned.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     Poisoned(PoisonError<T>),
     /// Returned when a lock failed to be acquired because the thread attempting to acquire
     /// the lock was already holding the lock.
     LockedByCurrentThread,
+    /// Returned when a lock failed to be acquired because the lock was already held by a thread
+    /// (other than the thread attempting to acquire the lock).
+    ///
+    /// [`ThreadCheckedMutex::lock`] blocks instead of returning this error; it can only occur
+    /// when converting a [`TryLockError`] (or [`std::sync::TryLockError`]) into a `LockError`.
+    ///
+    /// [`ThreadCheckedMutex::lock`]: super::mutex::ThreadCheckedMutex::lock
+    WouldBlock,
 }
 
 impl<T> LockError<T> {
     /// Silently converts any poison error into a successful result (see
     /// [`PoisonError::into_inner`]), and otherwise returns the error unchanged in an [`Err`].
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     ///
     /// # Errors
     /// If the provided error was not caused by poison, that error is returned.
@@ -112,6 +218,7 @@ impl<T> LockError<T> {
         match self {
             Self::Poisoned(poison)      => Ok(poison.into_inner()),
             Self::LockedByCurrentThread => Err(LockError::LockedByCurrentThread),
+            Self::WouldBlock            => Err(LockError::WouldBlock),
         }
     }
 
@@ -120,7 +227,7 @@ impl<T> LockError<T> {
     /// # Panics
     /// Panics if the error was caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     #[must_use]
     pub fn panic_if_poison(self) -> LockError<Infallible> {
@@ -131,8 +238,27 @@ impl<T> LockError<T> {
             )]
             Self::Poisoned(_)           => panic!("LockError was poison"),
             Self::LockedByCurrentThread => LockError::LockedByCurrentThread,
+            Self::WouldBlock            => LockError::WouldBlock,
         }
     }
+
+    /// Converts this error into a boxed, `'static` error which can be sent between threads,
+    /// without requiring anything of `T`. Any data in a poison error is dropped.
+    ///
+    /// The boxed error is a [`LockError<()>`], which can be recovered with
+    /// <code>[Box]\<dyn [Error]\>::downcast</code>.
+    ///
+    /// [`LockError<()>`]: LockError
+    #[inline]
+    #[must_use]
+    pub fn into_dyn_error(self) -> Box<dyn Error + Send + Sync> {
+        let erased = match self {
+            Self::Poisoned(poison)      => LockError::Poisoned(erase_poison(poison)),
+            Self::LockedByCurrentThread => LockError::LockedByCurrentThread,
+            Self::WouldBlock            => LockError::WouldBlock,
+        };
+        Box::new(erased)
+    }
 }
 
 impl<T> From<PoisonError<T>> for LockError<T> {
@@ -142,11 +268,61 @@ impl<T> From<PoisonError<T>> for LockError<T> {
     }
 }
 
+impl<T> From<TryLockError<T>> for LockError<T> {
+    #[inline]
+    fn from(error: TryLockError<T>) -> Self {
+        match error {
+            TryLockError::Poisoned(poison)      => Self::Poisoned(poison),
+            TryLockError::LockedByCurrentThread => Self::LockedByCurrentThread,
+            TryLockError::WouldBlock            => Self::WouldBlock,
+        }
+    }
+}
+
+impl<T> From<StdTryLockError<T>> for LockError<T> {
+    #[inline]
+    fn from(error: StdTryLockError<T>) -> Self {
+        match error {
+            StdTryLockError::Poisoned(poison) => Self::Poisoned(poison),
+            StdTryLockError::WouldBlock       => Self::WouldBlock,
+        }
+    }
+}
+
+/// Converts a [`LockError`] into the error that [`std::sync::Mutex::try_lock`] would have
+/// returned in the same situation.
+///
+/// This conversion is lossy: [`LockError::LockedByCurrentThread`] becomes
+/// [`std::sync::TryLockError::WouldBlock`], since the standard library does not distinguish
+/// which thread holds a lock.
+impl<T> From<LockError<T>> for StdTryLockError<T> {
+    #[inline]
+    fn from(error: LockError<T>) -> Self {
+        match error {
+            LockError::Poisoned(poison)      => Self::Poisoned(poison),
+            LockError::LockedByCurrentThread
+            | LockError::WouldBlock          => Self::WouldBlock,
+        }
+    }
+}
+
+impl<T: Clone> Clone for LockError<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Self::Poisoned(poison)      => Self::Poisoned(clone_poison(poison)),
+            Self::LockedByCurrentThread => Self::LockedByCurrentThread,
+            Self::WouldBlock            => Self::WouldBlock,
+        }
+    }
+}
+
 impl<T> Debug for LockError<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Poisoned(poison)      => f.debug_tuple("Poisoned").field(&poison).finish(),
             Self::LockedByCurrentThread => f.write_str("LockedByCurrentThread"),
+            Self::WouldBlock            => f.write_str("WouldBlock"),
         }
     }
 }
@@ -162,6 +338,10 @@ impl<T> Display for LockError<T> {
                 f,
                 "Failed to acquire a lock, because the same thread was holding it",
             ),
+            Self::WouldBlock => write!(
+                f,
+                "Lock was held by a different thread, so acquiring it would block",
+            ),
         }
     }
 }
@@ -170,10 +350,10 @@ impl<T> Error for LockError<T> {}
 
 impl PartialEq for LockError<Infallible> {
     #[inline]
-    fn eq(&self, _other: &Self) -> bool {
-        // There's only one inhabited variant of `LockError<Infallible>`, so this returns true.
+    fn eq(&self, other: &Self) -> bool {
         match self {
-            Self::LockedByCurrentThread => true,
+            Self::LockedByCurrentThread => matches!(other, Self::LockedByCurrentThread),
+            Self::WouldBlock            => matches!(other, Self::WouldBlock),
             Self::Poisoned(poison)      => prove_unreachable(poison),
         }
     }
@@ -195,7 +375,7 @@ impl<T> HandlePoisonResult for TryLockResult<T> {
     /// Silently converts any poison error into a successful result (see
     /// [`PoisonError::into_inner`]), and otherwise returns the result unchanged.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn ignore_poison(self) -> Self::PoisonlessResult {
         match self.map_err(TryLockError::ignore_poison) {
@@ -209,7 +389,7 @@ impl<T> HandlePoisonResult for TryLockResult<T> {
     /// # Panics
     /// Panics if the result is an [`Err`] that was caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn panic_if_poison(self) -> Self::PoisonlessResult {
         self.map_err(TryLockError::panic_if_poison)
@@ -222,7 +402,7 @@ impl<T> HandlePoisonResult for TryLockResult<T> {
 pub enum TryLockError<T> {
     /// Returned when a lock was acquired, but the lock was poisoned.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     Poisoned(PoisonError<T>),
     /// Returned when a lock failed to be acquired because the thread attempting to acquire
     /// the lock was already holding the lock.
@@ -236,7 +416,7 @@ impl<T> TryLockError<T> {
     /// Silently converts any poison error into a successful result (see
     /// [`PoisonError::into_inner`]), and otherwise returns the error unchanged in an [`Err`].
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     ///
     /// # Errors
     ///
@@ -255,7 +435,7 @@ impl<T> TryLockError<T> {
     /// # Panics
     /// Panics if the error was caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     #[must_use]
     pub fn panic_if_poison(self) -> TryLockError<Infallible> {
@@ -269,6 +449,24 @@ impl<T> TryLockError<T> {
             Self::WouldBlock            => TryLockError::WouldBlock,
         }
     }
+
+    /// Converts this error into a boxed, `'static` error which can be sent between threads,
+    /// without requiring anything of `T`. Any data in a poison error is dropped.
+    ///
+    /// The boxed error is a [`TryLockError<()>`], which can be recovered with
+    /// <code>[Box]\<dyn [Error]\>::downcast</code>.
+    ///
+    /// [`TryLockError<()>`]: TryLockError
+    #[inline]
+    #[must_use]
+    pub fn into_dyn_error(self) -> Box<dyn Error + Send + Sync> {
+        let erased = match self {
+            Self::Poisoned(poison)      => TryLockError::Poisoned(erase_poison(poison)),
+            Self::LockedByCurrentThread => TryLockError::LockedByCurrentThread,
+            Self::WouldBlock            => TryLockError::WouldBlock,
+        };
+        Box::new(erased)
+    }
 }
 
 impl<T> From<PoisonError<T>> for TryLockError<T> {
@@ -278,6 +476,55 @@ impl<T> From<PoisonError<T>> for TryLockError<T> {
     }
 }
 
+impl<T> From<LockError<T>> for TryLockError<T> {
+    #[inline]
+    fn from(error: LockError<T>) -> Self {
+        match error {
+            LockError::Poisoned(poison)      => Self::Poisoned(poison),
+            LockError::LockedByCurrentThread => Self::LockedByCurrentThread,
+            LockError::WouldBlock            => Self::WouldBlock,
+        }
+    }
+}
+
+impl<T> From<StdTryLockError<T>> for TryLockError<T> {
+    #[inline]
+    fn from(error: StdTryLockError<T>) -> Self {
+        match error {
+            StdTryLockError::Poisoned(poison) => Self::Poisoned(poison),
+            StdTryLockError::WouldBlock       => Self::WouldBlock,
+        }
+    }
+}
+
+/// Converts a [`TryLockError`] into the error that [`std::sync::Mutex::try_lock`] would have
+/// returned in the same situation.
+///
+/// This conversion is lossy: [`TryLockError::LockedByCurrentThread`] becomes
+/// [`std::sync::TryLockError::WouldBlock`], since the standard library does not distinguish
+/// which thread holds a lock.
+impl<T> From<TryLockError<T>> for StdTryLockError<T> {
+    #[inline]
+    fn from(error: TryLockError<T>) -> Self {
+        match error {
+            TryLockError::Poisoned(poison)      => Self::Poisoned(poison),
+            TryLockError::LockedByCurrentThread
+            | TryLockError::WouldBlock          => Self::WouldBlock,
+        }
+    }
+}
+
+impl<T: Clone> Clone for TryLockError<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Self::Poisoned(poison)      => Self::Poisoned(clone_poison(poison)),
+            Self::LockedByCurrentThread => Self::LockedByCurrentThread,
+            Self::WouldBlock            => Self::WouldBlock,
+        }
+    }
+}
+
 impl<T> Debug for TryLockError<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -342,7 +589,7 @@ impl<T> HandlePoisonResult for AccessResult<T> {
     ///
     /// Since every [`AccessError`] is caused by poison, the returned result is always [`Ok`].
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn ignore_poison(self) -> Self::PoisonlessResult {
         match self.map_err(AccessError::ignore_poison) {
@@ -359,7 +606,7 @@ impl<T> HandlePoisonResult for AccessResult<T> {
     /// # Panics
     /// Panics if the result is an [`Err`], which was necessarily caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     fn panic_if_poison(self) -> Self::PoisonlessResult {
         self.map_err(|err| AccessError::panic_if_poison(err))
@@ -368,7 +615,7 @@ impl<T> HandlePoisonResult for AccessResult<T> {
 
 /// Returned when a lock's data was accessed, but the lock was poisoned.
 ///
-/// [Read more about poison.](HandlePoisonResult#about-poison)
+/// [Read more about poison](HandlePoisonResult#about-poison).
 ///
 /// This error may be returned by [`ThreadCheckedMutex::into_inner`] or
 /// [`ThreadCheckedMutex::get_mut`].
@@ -386,7 +633,7 @@ impl<T> AccessError<T> {
     ///
     /// Since every [`AccessError`] is caused by poison, the returned result is always [`Ok`].
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[expect(clippy::missing_errors_doc, reason = "the function is infallible")]
     #[inline]
     pub fn ignore_poison(self) -> PoisonlessAccessResult<T> {
@@ -399,7 +646,7 @@ impl<T> AccessError<T> {
     /// # Panics
     /// Panics unconditionally, as the error is necessarily caused by poison.
     ///
-    /// [Read more about poison.](HandlePoisonResult#about-poison)
+    /// [Read more about poison](HandlePoisonResult#about-poison).
     #[inline]
     pub fn panic_if_poison(self) -> ! {
         #![expect(
@@ -408,6 +655,19 @@ impl<T> AccessError<T> {
         )]
         panic!("AccessError is poison")
     }
+
+    /// Converts this error into a boxed, `'static` error which can be sent between threads,
+    /// without requiring anything of `T`. The data in the poison error is dropped.
+    ///
+    /// The boxed error is an [`AccessError<()>`], which can be recovered with
+    /// <code>[Box]\<dyn [Error]\>::downcast</code>.
+    ///
+    /// [`AccessError<()>`]: AccessError
+    #[inline]
+    #[must_use]
+    pub fn into_dyn_error(self) -> Box<dyn Error + Send + Sync> {
+        Box::new(AccessError { poison: erase_poison(self.poison) })
+    }
 }
 
 impl<T> From<PoisonError<T>> for AccessError<T> {
@@ -417,6 +677,15 @@ impl<T> From<PoisonError<T>> for AccessError<T> {
     }
 }
 
+impl<T: Clone> Clone for AccessError<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            poison: clone_poison(&self.poison),
+        }
+    }
+}
+
 impl<T> Debug for AccessError<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("AccessError")
@@ -552,11 +821,10 @@ mod tests {
     fn panicking_access_panic_if_poison() {
         // Poison
         let res_p: AccessResult<()> = Err(PoisonError::new(()).into());
-        #[expect(
-            clippy::let_underscore_must_use,
-            clippy::let_underscore_untyped,
-            reason = "function never returns",
-        )]
+        // Newer versions of clippy recognize that the poisonless result has an uninhabited error,
+        // and do not emit `let_underscore_must_use`.
+        #[allow(clippy::let_underscore_must_use, reason = "function never returns")]
+        #[expect(clippy::let_underscore_untyped, reason = "function never returns")]
         let _ = res_p.panic_if_poison();
     }
 
@@ -573,6 +841,7 @@ mod tests {
         // The `::<Infallible>`s are not strictly necessary, but make it more clear.
         test_eq_impl(&[
             LockError::<Infallible>::LockedByCurrentThread,
+            LockError::<Infallible>::WouldBlock,
         ]);
         test_eq_impl(&[
             TryLockError::<Infallible>::LockedByCurrentThread,
@@ -580,4 +849,127 @@ mod tests {
         ]);
         // `AccessError<Infallible>` is uninhabited.
     }
+
+    #[test]
+    fn clone_impls() {
+        let lock_poison: LockError<u8> = PoisonError::new(1).into();
+        let lock_clone = lock_poison.clone();
+        for error in [lock_poison, lock_clone] {
+            assert!(matches!(error, LockError::Poisoned(poison) if *poison.get_ref() == 1));
+        }
+
+        let try_lock_poison: TryLockError<u8> = PoisonError::new(2).into();
+        let try_lock_clone = try_lock_poison.clone();
+        for error in [try_lock_poison, try_lock_clone] {
+            assert!(matches!(error, TryLockError::Poisoned(poison) if *poison.get_ref() == 2));
+        }
+
+        let would_block: TryLockError<u8> = TryLockError::WouldBlock;
+        let would_block_clone = would_block.clone();
+        for error in [would_block, would_block_clone] {
+            assert!(matches!(error, TryLockError::WouldBlock));
+        }
+
+        let access_poison: AccessError<u8> = PoisonError::new(3).into();
+        let access_clone = access_poison.clone();
+        for error in [access_poison, access_clone] {
+            assert_eq!(*error.poison.get_ref(), 3);
+        }
+    }
+
+    #[test]
+    fn option_ignore_poison() {
+        let none: Option<LockResult<()>> = None;
+        assert!(none.ignore_poison().is_none());
+
+        let poison: Option<LockResult<()>> = Some(Err(PoisonError::new(()).into()));
+        assert!(matches!(poison.ignore_poison(), Some(Ok(()))));
+
+        let locked: Option<TryLockResult<()>> = Some(Err(TryLockError::WouldBlock));
+        assert!(matches!(locked.panic_if_poison(), Some(Err(TryLockError::WouldBlock))));
+    }
+
+    #[test]
+    fn ignore_all_poison() {
+        let results: Vec<LockResult<u8>> = vec![
+            Ok(1),
+            Err(PoisonError::new(2).into()),
+            Err(LockError::WouldBlock),
+        ];
+
+        let handled = results.ignore_all_poison().collect::<Vec<_>>();
+        assert!(matches!(handled.as_slice(), [Ok(1), Ok(2), Err(LockError::WouldBlock)]));
+
+        let try_results: Vec<TryLockResult<u8>> = vec![Ok(1), Err(TryLockError::WouldBlock)];
+        assert_eq!(try_results.panic_if_any_poison().count(), 2);
+    }
+
+    #[test]
+    fn lock_error_conversions() {
+        // `TryLockError` -> `LockError` -> `TryLockError` is lossless.
+        let locked: TryLockError<()> = TryLockError::LockedByCurrentThread;
+        let locked = LockError::from(locked);
+        assert!(matches!(locked, LockError::LockedByCurrentThread));
+        assert!(matches!(TryLockError::from(locked), TryLockError::LockedByCurrentThread));
+
+        let would_block: TryLockError<()> = TryLockError::WouldBlock;
+        let would_block = LockError::from(would_block);
+        assert!(matches!(would_block, LockError::WouldBlock));
+        assert!(matches!(TryLockError::from(would_block), TryLockError::WouldBlock));
+
+        let poison: TryLockError<()> = PoisonError::new(()).into();
+        let poison = LockError::from(poison);
+        assert!(matches!(poison, LockError::Poisoned(_)));
+        assert!(matches!(TryLockError::from(poison), TryLockError::Poisoned(_)));
+
+        // Poisonless errors convert as well.
+        let poisonless: TryLockError<Infallible> = TryLockError::WouldBlock;
+        assert_eq!(LockError::from(poisonless), LockError::WouldBlock);
+    }
+
+    #[test]
+    fn std_try_lock_error_conversions() {
+        let std_would_block = || StdTryLockError::<()>::WouldBlock;
+        assert!(matches!(LockError::from(std_would_block()), LockError::WouldBlock));
+        assert!(matches!(TryLockError::from(std_would_block()), TryLockError::WouldBlock));
+
+        let std_poison: StdTryLockError<()> = PoisonError::new(()).into();
+        assert!(matches!(TryLockError::from(std_poison), TryLockError::Poisoned(_)));
+
+        // The standard library cannot distinguish which thread holds a lock.
+        let lock_locked: LockError<()> = LockError::LockedByCurrentThread;
+        assert!(matches!(StdTryLockError::from(lock_locked), StdTryLockError::WouldBlock));
+
+        let try_lock_locked: TryLockError<()> = TryLockError::LockedByCurrentThread;
+        assert!(matches!(StdTryLockError::from(try_lock_locked), StdTryLockError::WouldBlock));
+
+        let poison: LockError<()> = PoisonError::new(()).into();
+        assert!(matches!(StdTryLockError::from(poison), StdTryLockError::Poisoned(_)));
+    }
+
+    #[test]
+    fn into_dyn_error() {
+        #![expect(clippy::unwrap_used, reason = "these are tests")]
+
+        use std::rc::Rc;
+
+        // The poisoned data is not `Send` or `Sync`, and is dropped.
+        let lock_poison: LockError<Rc<u8>> = PoisonError::new(Rc::new(0)).into();
+        let lock_poison = lock_poison.into_dyn_error();
+        assert_eq!(
+            lock_poison.to_string(),
+            "LockError due to poison (another thread panicked)",
+        );
+        assert!(matches!(*lock_poison.downcast().unwrap(), LockError::<()>::Poisoned(_)));
+
+        let try_lock_locked = TryLockError::<Rc<u8>>::LockedByCurrentThread.into_dyn_error();
+        assert!(matches!(
+            *try_lock_locked.downcast().unwrap(),
+            TryLockError::<()>::LockedByCurrentThread,
+        ));
+
+        let access_poison: AccessError<Rc<u8>> = PoisonError::new(Rc::new(0)).into();
+        let access_poison = access_poison.into_dyn_error();
+        assert!(access_poison.is::<AccessError<()>>());
+    }
 }
@@ -0,0 +1,79 @@
+#![expect(
+    clippy::redundant_pub_crate,
+    reason = "reemphasize that these are all internals",
+)]
+
+use std::collections::VecDeque;
+use std::thread::{self, Thread};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+
+/// A FIFO queue of threads, placed in front of a fair [`ThreadCheckedMutex`]'s inner mutex.
+///
+/// The thread at the front of the queue is the thread which holds (or is about to acquire) the
+/// inner mutex; every other queued thread is parked until it reaches the front.
+///
+/// [`ThreadCheckedMutex`]: crate::ThreadCheckedMutex
+#[derive(Debug, Default)]
+pub(crate) struct FairQueue {
+    waiting: Mutex<VecDeque<Thread>>,
+}
+
+impl FairQueue {
+    /// Lock the queue itself. The queue is never left in an inconsistent state, so poison is
+    /// ignored.
+    fn waiting(&self) -> MutexGuard<'_, VecDeque<Thread>> {
+        self.waiting.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Join the back of the queue, and park the current thread until it reaches the front.
+    pub(crate) fn enter(&self) {
+        let current = thread::current();
+        let current_id = current.id();
+
+        let mut waiting = self.waiting();
+        waiting.push_back(current);
+
+        while waiting.front().map(Thread::id) != Some(current_id) {
+            drop(waiting);
+            // If `release` unparks this thread before it parks, `park` returns immediately.
+            thread::park();
+            waiting = self.waiting();
+        }
+    }
+
+    /// Join the queue only if it is empty, without blocking. Returns whether the current thread
+    /// is now at the front of the queue.
+    #[must_use]
+    pub(crate) fn try_enter(&self) -> bool {
+        let mut waiting = self.waiting();
+        if waiting.is_empty() {
+            waiting.push_back(thread::current());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Leave the front of the queue, and wake the next queued thread, if any.
+    ///
+    /// Must only be called by the thread at the front of the queue.
+    pub(crate) fn release(&self) {
+        let mut waiting = self.waiting();
+        let released = waiting.pop_front();
+        debug_assert!(
+            released.is_some_and(|released| released.id() == thread::current().id()),
+            "a FairQueue was released by a thread which was not at its front",
+        );
+
+        if let Some(next) = waiting.front() {
+            next.unpark();
+        }
+    }
+
+    /// The number of threads in the queue, including the thread at its front.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.waiting().len()
+    }
+}
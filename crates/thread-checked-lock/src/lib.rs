@@ -16,17 +16,30 @@
 
 mod mutex;
 mod error;
+mod fair_queue;
+#[cfg(feature = "metrics")]
+mod lock_stats;
 
 mod locked_mutexes;
 mod locked_mutexes_inner;
 mod mutex_id;
 
 
+// The dev-dependencies are only used in benchmarks, which still triggers the
+// `unused_crate_dependencies` lint.
+#[cfg(test)]
+use criterion as _;
+#[cfg(test)]
+use parking_lot as _;
+
+
 pub use self::{
     error::{
-        AccessError, AccessResult, HandlePoisonResult, LockError, LockResult,
-        PoisonlessAccessResult, PoisonlessLockResult, PoisonlessTryLockResult,
+        AccessError, AccessResult, HandleAllPoison, HandlePoisonResult, LockError, LockResult,
+        PoisonlessAccessResult, PoisonlessIter, PoisonlessLockResult, PoisonlessTryLockResult,
         TryLockError, TryLockResult,
     },
     mutex::{ThreadCheckedMutex, ThreadCheckedMutexGuard},
 };
+#[cfg(feature = "metrics")]
+pub use self::lock_stats::LockStats;
@@ -0,0 +1,79 @@
+#![expect(
+    clippy::redundant_pub_crate,
+    reason = "reemphasize that the counters are internals",
+)]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+
+/// A snapshot of the counters of a [`ThreadCheckedMutex`], returned by
+/// [`ThreadCheckedMutex::stats`].
+///
+/// Only attempts made through [`lock`] and [`try_lock`] (and the functions which use them, such
+/// as [`scope`]) are counted; [`lock_unchecked`] bypasses the counters, as does relocking the
+/// mutex in [`ThreadCheckedMutexGuard::unlocked`].
+///
+/// The counters are updated independently of each other with relaxed atomic operations, so a
+/// snapshot taken while other threads use the mutex may be slightly inconsistent.
+///
+/// [`ThreadCheckedMutex`]: crate::ThreadCheckedMutex
+/// [`ThreadCheckedMutex::stats`]: crate::ThreadCheckedMutex::stats
+/// [`ThreadCheckedMutexGuard::unlocked`]: crate::ThreadCheckedMutexGuard::unlocked
+/// [`lock`]: crate::ThreadCheckedMutex::lock
+/// [`try_lock`]: crate::ThreadCheckedMutex::try_lock
+/// [`scope`]: crate::ThreadCheckedMutex::scope
+/// [`lock_unchecked`]: crate::ThreadCheckedMutex::lock_unchecked
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LockStats {
+    /// The number of times the mutex was acquired, including acquisitions which returned a poison
+    /// error.
+    pub acquisitions:         u64,
+    /// The number of attempts which found the mutex held by another thread (or, for a [fair]
+    /// mutex, found other threads queued for it). For [`lock`], this is the number of calls
+    /// which had to block; for [`try_lock`], this is the number of [`WouldBlock`] errors.
+    ///
+    /// [fair]: crate::ThreadCheckedMutex::new_fair
+    /// [`lock`]: crate::ThreadCheckedMutex::lock
+    /// [`try_lock`]: crate::ThreadCheckedMutex::try_lock
+    /// [`WouldBlock`]: crate::TryLockError::WouldBlock
+    pub contended:            u64,
+    /// The number of attempts which were rejected with a `LockedByCurrentThread` error, because
+    /// the current thread already held the mutex.
+    pub reentrant_rejections: u64,
+}
+
+/// The atomic counters backing [`LockStats`], stored in each `ThreadCheckedMutex`.
+#[derive(Debug, Default)]
+pub(crate) struct LockCounters {
+    acquisitions:         AtomicU64,
+    contended:            AtomicU64,
+    reentrant_rejections: AtomicU64,
+}
+
+impl LockCounters {
+    #[inline]
+    pub(crate) fn record_acquisition(&self) {
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn record_contended(&self) {
+        self.contended.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn record_reentrant_rejection(&self) {
+        self.reentrant_rejections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Read the current value of each counter.
+    #[inline]
+    #[must_use]
+    pub(crate) fn snapshot(&self) -> LockStats {
+        LockStats {
+            acquisitions:         self.acquisitions.load(Ordering::Relaxed),
+            contended:            self.contended.load(Ordering::Relaxed),
+            reentrant_rejections: self.reentrant_rejections.load(Ordering::Relaxed),
+        }
+    }
+}
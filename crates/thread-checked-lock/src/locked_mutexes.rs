@@ -18,7 +18,8 @@ type Inner = LockedMutexesInner<4>;
 thread_local! {
     /// Follow the below requirement when using this static.
     /// # Safety Requirement
-    /// This TLS must only be accessed by `access_locked_mutexes`.
+    /// This TLS must only be accessed by `access_locked_mutexes` and
+    /// `try_access_locked_mutexes`.
     /// Unsafe code may rely on this requirement.
     static LOCKED_MUTEXES: UnsafeCell<Inner> = UnsafeCell::default();
 }
@@ -64,6 +65,35 @@ where
     })
 }
 
+/// Like `access_locked_mutexes`, but returns `None` instead of panicking if the
+/// thread-local has already been destroyed (for instance, if this is called from the
+/// destructor of another thread-local).
+///
+/// # Safety
+/// - The given callback `f` must not call `access_locked_mutexes` or
+///   `try_access_locked_mutexes`.
+/// - The return value of `f` must not borrow/reference the
+///   `&mut Inner` provided to `f` in any way.
+#[cfg(feature = "recycle-ids")]
+unsafe fn try_access_locked_mutexes<F, R>(f: F) -> Option<R>
+where
+    F: FnOnce(&mut Inner) -> R,
+{
+    // SAFETY REQUIREMENT:
+    // We are in a function permitted to access `LOCKED_MUTEXES`.
+    LOCKED_MUTEXES.try_with(|inner| {
+        let locked_mutexes: *mut Inner = inner.get();
+
+        // SAFETY:
+        // The same reasoning as in `access_locked_mutexes` applies,
+        // as the caller makes the same promises about `f`, and
+        // neither function calls the other.
+        let locked_mutexes: &mut Inner = unsafe { &mut *locked_mutexes };
+
+        f(locked_mutexes)
+    }).ok()
+}
+
 /// Returns `true` iff `mutex_id` was not previously locked. In either case, `mutex_id` is
 /// registered as locked when this function returns.
 ///
@@ -103,3 +133,19 @@ pub(crate) fn locked_by_current_thread(mutex_id: MutexID) -> bool {
     // - The return value, `bool`, does not reference anything.
     unsafe { access_locked_mutexes(|lm_inner| lm_inner.locked_by_current_thread(mutex_id)) }
 }
+
+/// Returns `Some(true)` iff `mutex_id` was locked, or `None` if the current thread's registry
+/// of locked mutexes has already been destroyed.
+#[cfg(feature = "recycle-ids")]
+#[inline]
+#[must_use]
+pub(crate) fn try_locked_by_current_thread(mutex_id: MutexID) -> Option<bool> {
+    // SAFETY:
+    // - The callback does not call `access_locked_mutexes` or
+    //   `try_access_locked_mutexes`, as the `locked_mutexes_inner`
+    //   module does not import anything from this module.
+    // - The return value, `bool`, does not reference anything.
+    unsafe {
+        try_access_locked_mutexes(|lm_inner| lm_inner.locked_by_current_thread(mutex_id))
+    }
+}
@@ -1,15 +1,23 @@
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Deref, DerefMut},
-    sync::{Mutex, MutexGuard, PoisonError, TryLockError as StdTryLockError},
+    sync::{LockResult as StdLockResult, PoisonError},
+    thread::yield_now,
 };
+use std::sync::{Mutex, MutexGuard, TryLockError as StdTryLockError};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{locked_mutexes, mutex_id};
-use crate::mutex_id::MutexID;
-use crate::error::{AccessResult, LockError, LockResult, TryLockError, TryLockResult};
+use crate::locked_mutexes;
+#[cfg(feature = "metrics")]
+use crate::lock_stats::{LockCounters, LockStats};
+use crate::fair_queue::FairQueue;
+use crate::mutex_id::OwnedMutexID;
+use crate::error::{
+    AccessResult, HandlePoisonResult as _, LockError, LockResult, PoisonlessLockResult,
+    PoisonlessTryLockResult, TryLockError, TryLockResult,
+};
 
 
 /// A variant of [`std::sync::Mutex`] which gracefully returns an error when a thread attempts
@@ -19,11 +27,32 @@ use crate::error::{AccessResult, LockError, LockResult, TryLockError, TryLockRes
 /// [`Mutex::try_lock`] checks if *any* thread holds the lock (and cannot distinguish whether the
 /// current thread holds the lock). As such, attempting to lock the same `Mutex` twice on a thread
 /// is potentially a fatal error; `ThreadCheckedMutex` allows for recovery.
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// When the `serde` feature is enabled, a `ThreadCheckedMutex<T>` is serialized exactly as its
+/// inner [`Mutex<T>`] is. The mutex's [`id`] is process-local, so it is not serialized; a
+/// deserialized mutex is assigned a fresh ID.
+///
+/// [`id`]: ThreadCheckedMutex::id
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 #[derive(Debug)]
 pub struct ThreadCheckedMutex<T: ?Sized> {
-    mutex_id: MutexID,
-    mutex:    Mutex<T>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "OwnedMutexID::new"))]
+    mutex_id:      OwnedMutexID,
+    /// Whether [`lock`] and [`try_lock`] clear and ignore poison.
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ignore_poison: bool,
+    /// Only `Some` for mutexes created with [`new_fair`].
+    ///
+    /// [`new_fair`]: ThreadCheckedMutex::new_fair
+    #[cfg_attr(feature = "serde", serde(skip))]
+    queue:         Option<Box<FairQueue>>,
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    counters:      LockCounters,
+    mutex:         Mutex<T>,
 }
 
 impl<T> ThreadCheckedMutex<T> {
@@ -32,8 +61,88 @@ impl<T> ThreadCheckedMutex<T> {
     #[must_use]
     pub fn new(t: T) -> Self {
         Self {
-            mutex_id: mutex_id::next_id(),
-            mutex:    Mutex::new(t),
+            mutex_id:      OwnedMutexID::new(),
+            ignore_poison: false,
+            queue:         None,
+            #[cfg(feature = "metrics")]
+            counters:      LockCounters::default(),
+            mutex:         Mutex::new(t),
+        }
+    }
+
+    /// Creates a new mutex in an unlocked state, which clears and ignores poison whenever it is
+    /// locked.
+    ///
+    /// [`lock`] and [`try_lock`] (and functions which use them, such as [`scope`]) never return
+    /// a poison error for the returned mutex; if the mutex was poisoned, the poison is cleared
+    /// while the lock is held, as in [`lock_or_clear_poison`], and the guard is returned in
+    /// [`Ok`]. This matches the behavior of [`parking_lot`]'s locks, without callers needing to
+    /// handle poison on every call. Their return types are unchanged, so a poison error variant
+    /// must still be matched, though it is never constructed.
+    ///
+    /// Other functions, such as [`into_inner`] and [`get_mut`], still report poison. This setting
+    /// is not serialized; a deserialized mutex uses the default behavior of [`new`].
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`scope`]: ThreadCheckedMutex::scope
+    /// [`lock_or_clear_poison`]: ThreadCheckedMutex::lock_or_clear_poison
+    /// [`into_inner`]: ThreadCheckedMutex::into_inner
+    /// [`get_mut`]: ThreadCheckedMutex::get_mut
+    /// [`new`]: ThreadCheckedMutex::new
+    /// [`parking_lot`]: https://docs.rs/parking_lot/
+    #[inline]
+    #[must_use]
+    pub fn new_ignore_poison(t: T) -> Self {
+        Self {
+            mutex_id:      OwnedMutexID::new(),
+            ignore_poison: true,
+            queue:         None,
+            #[cfg(feature = "metrics")]
+            counters:      LockCounters::default(),
+            mutex:         Mutex::new(t),
+        }
+    }
+
+    /// Creates a new mutex in an unlocked state, which is acquired by [`lock`] in approximately
+    /// the order in which threads attempt to acquire it.
+    ///
+    /// Under heavy contention, the inner mutex may repeatedly be reacquired by the same threads
+    /// while others are starved. A fair mutex avoids this by placing a FIFO queue of threads in
+    /// front of the inner mutex: each call to [`lock`] joins the back of the queue, and the
+    /// calling thread is parked until every thread ahead of it has unlocked the mutex. The
+    /// reentrancy check happens before the thread joins the queue, so [`lock`] still returns a
+    /// [`LockedByCurrentThread`] error instead of waiting on itself. [`try_lock`] only succeeds
+    /// if no thread is queued.
+    ///
+    /// # Throughput
+    /// Fairness is paid for with throughput. Every lock and unlock also locks the queue, and since
+    /// the mutex is handed to the next queued thread (which must first be woken up) rather than
+    /// to whichever thread is running, a thread can no longer quickly reacquire a mutex it just
+    /// released. Prefer [`new`] unless starvation is an actual problem.
+    ///
+    /// # Unchecked Acquisition
+    /// [`lock_unchecked`] and [`ThreadCheckedMutexGuard::into_std_guard`] bypass the queue; a
+    /// thread which holds the mutex through them delays queued threads, but does not otherwise
+    /// affect the order in which they acquire the mutex.
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`new`]: ThreadCheckedMutex::new
+    /// [`lock_unchecked`]: ThreadCheckedMutex::lock_unchecked
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    #[inline]
+    #[must_use]
+    pub fn new_fair(t: T) -> Self {
+        Self {
+            mutex_id:      OwnedMutexID::new(),
+            ignore_poison: false,
+            queue:         Some(Box::default()),
+            #[cfg(feature = "metrics")]
+            counters:      LockCounters::default(),
+            mutex:         Mutex::new(t),
         }
     }
 }
@@ -41,10 +150,68 @@ impl<T> ThreadCheckedMutex<T> {
 impl<T: ?Sized> ThreadCheckedMutex<T> {
     /// Helper function for creating a [`ThreadCheckedMutexGuard`] from a [`MutexGuard`].
     #[inline]
-    const fn new_guard<'a>(&self, guard: MutexGuard<'a, T>) -> ThreadCheckedMutexGuard<'a, T> {
+    fn new_guard<'a>(
+        &'a self,
+        guard: MutexGuard<'a, T>,
+    ) -> ThreadCheckedMutexGuard<'a, T> {
+        #[cfg(feature = "metrics")]
+        self.counters.record_acquisition();
+        #[cfg(feature = "recycle-ids")]
+        self.mutex_id.guard_created();
+
         ThreadCheckedMutexGuard {
-            mutex_id: self.mutex_id,
-            guard,
+            mutex_id: &self.mutex_id,
+            mutex:    &self.mutex,
+            queue:    self.queue.as_deref(),
+            guard:    Some(guard),
+        }
+    }
+
+    /// Helper function for leaving the queue of a fair mutex, if this mutex is fair.
+    #[inline]
+    fn leave_queue(&self) {
+        if let Some(queue) = &self.queue {
+            queue.release();
+        }
+    }
+
+    /// Helper function for joining the queue of a fair mutex (if this mutex is fair), and then
+    /// blocking until the inner mutex is acquired.
+    ///
+    /// With the `metrics` feature, the queue and the inner mutex are first tried without
+    /// blocking, so that an acquisition which has to wait for another thread is counted as
+    /// contended without locking the inner mutex an extra time.
+    #[inline]
+    fn acquire(&self) -> StdLockResult<MutexGuard<'_, T>> {
+        #[cfg(feature = "metrics")]
+        {
+            let mut contended = false;
+            if let Some(queue) = &self.queue {
+                if !queue.try_enter() {
+                    contended = true;
+                    self.counters.record_contended();
+                    queue.enter();
+                }
+            }
+
+            match self.mutex.try_lock() {
+                Ok(guard)                              => Ok(guard),
+                Err(StdTryLockError::Poisoned(poison)) => Err(poison),
+                Err(StdTryLockError::WouldBlock)       => {
+                    if !contended {
+                        self.counters.record_contended();
+                    }
+                    self.mutex.lock()
+                }
+            }
+        }
+
+        #[cfg(not(feature = "metrics"))]
+        {
+            if let Some(queue) = &self.queue {
+                queue.enter();
+            }
+            self.mutex.lock()
         }
     }
 
@@ -52,7 +219,7 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
     /// [`ThreadCheckedMutexGuard`].
     #[inline]
     fn poisoned_guard<'a>(
-        &self,
+        &'a self,
         poison: PoisonError<MutexGuard<'a, T>>,
     ) -> PoisonError<ThreadCheckedMutexGuard<'a, T>> {
         PoisonError::new(self.new_guard(poison.into_inner()))
@@ -68,6 +235,11 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
     /// most one [`ThreadCheckedMutexGuard`] can exist at a time (across any thread); and the mutex
     /// is unlocked when the returned guard is dropped.
     ///
+    /// The reentrancy check happens before blocking, so there is no need to call [`try_lock`]
+    /// first and fall back to `lock` on a [`WouldBlock`] error: `lock` alone returns
+    /// [`LockedByCurrentThread`] immediately if the current thread holds the mutex, and otherwise
+    /// only blocks while another thread holds it.
+    ///
     /// # Errors
     /// If the mutex was already held by the current thread when this call was made, then a
     /// [`LockedByCurrentThread`] error is returned.
@@ -79,20 +251,58 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
     ///
     /// [`HandlePoisonResult`]: crate::HandlePoisonResult
     /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`WouldBlock`]: TryLockError::WouldBlock
     pub fn lock(&self) -> LockResult<ThreadCheckedMutexGuard<'_, T>> {
-        if locked_mutexes::register_locked(self.mutex_id) {
-            match self.mutex.lock() {
-                Ok(guard)   => Ok(self.new_guard(guard)),
-                Err(poison) => {
-                    let poison = self.poisoned_guard(poison);
-                    Err(LockError::Poisoned(poison))
-                }
+        if !locked_mutexes::register_locked(self.mutex_id.id()) {
+            #[cfg(feature = "metrics")]
+            self.counters.record_reentrant_rejection();
+            return Err(LockError::LockedByCurrentThread);
+        }
+
+        match self.acquire() {
+            Ok(guard)   => Ok(self.new_guard(guard)),
+            Err(poison) if self.ignore_poison => {
+                self.mutex.clear_poison();
+                Ok(self.new_guard(poison.into_inner()))
+            }
+            Err(poison) => {
+                let poison = self.poisoned_guard(poison);
+                Err(LockError::Poisoned(poison))
             }
-        } else {
-            Err(LockError::LockedByCurrentThread)
         }
     }
 
+    /// Acquires this mutex without checking or recording whether the current thread holds it,
+    /// blocking the current thread while the mutex is locked in other threads.
+    ///
+    /// This behaves exactly like the underlying mutex's `lock` method, and returns its guard. It
+    /// skips the thread-local bookkeeping done by [`lock`], which may be worthwhile in hot paths
+    /// which are known to never reenter the mutex.
+    ///
+    /// # Forfeited Reentrancy Detection
+    /// While the returned guard is held, the current thread is *not* recorded as holding this
+    /// mutex: [`locked_by_current_thread`] returns `false`, and a call to [`lock`] (or to this
+    /// function) on the current thread may deadlock or panic instead of returning a
+    /// [`LockedByCurrentThread`] error. Likewise, calling this function while the current thread
+    /// holds a [`ThreadCheckedMutexGuard`] for this mutex may deadlock or panic. [`try_lock`]
+    /// returns a [`WouldBlock`] error in either case.
+    ///
+    /// # Errors
+    /// If another user of this mutex panicked while holding the mutex, then this call will still
+    /// acquire the mutex but wrap the returned guard in a poison error, as in
+    /// [`std::sync::Mutex::lock`].
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`locked_by_current_thread`]: ThreadCheckedMutex::locked_by_current_thread
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    #[inline]
+    pub fn lock_unchecked(&self) -> StdLockResult<MutexGuard<'_, T>> {
+        self.mutex.lock()
+    }
+
     /// Attempts to acquire this mutex without blocking.
     ///
     /// If the mutex is acquired (either completely successfully or with a poison error), a
@@ -115,9 +325,17 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
     /// [`WouldBlock`]: TryLockError::WouldBlock
     pub fn try_lock(&self) -> TryLockResult<ThreadCheckedMutexGuard<'_, T>> {
         if self.locked_by_current_thread() {
+            #[cfg(feature = "metrics")]
+            self.counters.record_reentrant_rejection();
             return Err(TryLockError::LockedByCurrentThread);
         }
 
+        if self.queue.as_ref().is_some_and(|queue| !queue.try_enter()) {
+            #[cfg(feature = "metrics")]
+            self.counters.record_contended();
+            return Err(TryLockError::WouldBlock);
+        }
+
         match self.mutex.try_lock() {
             Ok(guard) => {
                 #[expect(
@@ -126,7 +344,7 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
                     reason = "We already checked that the current thread hasn't locked the mutex, \
                               so this always returns true.",
                 )]
-                let _: bool = locked_mutexes::register_locked(self.mutex_id);
+                let _: bool = locked_mutexes::register_locked(self.mutex_id.id());
                 Ok(self.new_guard(guard))
             }
             Err(StdTryLockError::Poisoned(poison)) => {
@@ -136,19 +354,249 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
                     reason = "We already checked that the current thread hasn't locked the mutex, \
                               so this always returns true.",
                 )]
-                let _: bool = locked_mutexes::register_locked(self.mutex_id);
-                let poison = self.poisoned_guard(poison);
-                Err(TryLockError::Poisoned(poison))
+                let _: bool = locked_mutexes::register_locked(self.mutex_id.id());
+                if self.ignore_poison {
+                    self.mutex.clear_poison();
+                    Ok(self.new_guard(poison.into_inner()))
+                } else {
+                    let poison = self.poisoned_guard(poison);
+                    Err(TryLockError::Poisoned(poison))
+                }
+            }
+            Err(StdTryLockError::WouldBlock) => {
+                self.leave_queue();
+                #[cfg(feature = "metrics")]
+                self.counters.record_contended();
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
+    /// Attempts to acquire this mutex, blocking the current thread while the mutex is locked in
+    /// other threads, and ignoring any poison.
+    ///
+    /// Equivalent to `self.lock().ignore_poison()`. The returned error type cannot be a poison
+    /// error, which is convenient for callers that never care about poison (as with
+    /// [`parking_lot`]'s locks). Any poison is left in place; see [`clear_poison`].
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned.
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`clear_poison`]: ThreadCheckedMutex::clear_poison
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    /// [`parking_lot`]: https://docs.rs/parking_lot/
+    #[inline]
+    pub fn lock_ignore_poison(&self) -> PoisonlessLockResult<ThreadCheckedMutexGuard<'_, T>> {
+        self.lock().ignore_poison()
+    }
+
+    /// Attempts to acquire this mutex, blocking the current thread while the mutex is locked in
+    /// other threads, and clearing any poison.
+    ///
+    /// Unlike calling [`clear_poison`] and then [`lock`], the poison is cleared while the current
+    /// thread holds the lock, so no other thread can observe the data between the poison being
+    /// cleared and the returned guard being created. Callers should restore the protected data to
+    /// a valid state, if necessary, before dropping the returned guard.
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned.
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`clear_poison`]: ThreadCheckedMutex::clear_poison
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    #[inline]
+    pub fn lock_or_clear_poison(&self) -> PoisonlessLockResult<ThreadCheckedMutexGuard<'_, T>> {
+        self.lock_recover().map(|(guard, _)| guard)
+    }
+
+    /// Attempts to acquire this mutex, blocking the current thread while the mutex is locked in
+    /// other threads, and clearing any poison. Also returns whether the mutex was poisoned.
+    ///
+    /// This behaves like [`lock_or_clear_poison`], but additionally reports whether poison was
+    /// cleared. If the returned `bool` is `true`, another user of this mutex panicked while
+    /// holding it, and the protected data may have been left in an inconsistent state; callers
+    /// should check or repair the data's invariants before dropping the returned guard. No other
+    /// thread can observe the data in the meantime.
+    ///
+    /// If this mutex was created with [`new_ignore_poison`], then poison is always cleared by
+    /// [`lock`], and the returned `bool` is always `false`.
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::{panic, sync::Arc, thread};
+    /// use thread_checked_lock::ThreadCheckedMutex;
+    ///
+    /// let mutex = Arc::new(ThreadCheckedMutex::new(vec![1, 2, 3]));
+    /// let mutex_clone = Arc::clone(&mutex);
+    ///
+    /// let _ = thread::spawn(move || {
+    ///     let mut guard = mutex_clone.lock().unwrap();
+    ///     guard.push(4);
+    ///     panic!("left the data half-updated");
+    /// }).join();
+    ///
+    /// let (mut guard, was_poisoned) = mutex.lock_recover().unwrap();
+    /// assert!(was_poisoned);
+    /// guard.truncate(3);
+    /// drop(guard);
+    ///
+    /// assert!(!mutex.is_poisoned());
+    /// ```
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`lock_or_clear_poison`]: ThreadCheckedMutex::lock_or_clear_poison
+    /// [`new_ignore_poison`]: ThreadCheckedMutex::new_ignore_poison
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    pub fn lock_recover(&self) -> PoisonlessLockResult<(ThreadCheckedMutexGuard<'_, T>, bool)> {
+        match self.lock() {
+            Ok(guard) => Ok((guard, false)),
+            Err(LockError::Poisoned(poison)) => {
+                self.clear_poison();
+                Ok((poison.into_inner(), true))
+            }
+            Err(LockError::LockedByCurrentThread) => Err(LockError::LockedByCurrentThread),
+            Err(LockError::WouldBlock)            => Err(LockError::WouldBlock),
+        }
+    }
+
+    /// Attempts to acquire this mutex without blocking, ignoring any poison.
+    ///
+    /// Equivalent to `self.try_lock().ignore_poison()`. The returned error type cannot be a
+    /// poison error, which is convenient for callers that never care about poison (as with
+    /// [`parking_lot`]'s locks). Any poison is left in place; see [`clear_poison`].
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned. If the mutex was held by a different thread,
+    /// then a [`WouldBlock`] error is returned.
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`clear_poison`]: ThreadCheckedMutex::clear_poison
+    /// [`LockedByCurrentThread`]: TryLockError::LockedByCurrentThread
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    /// [`parking_lot`]: https://docs.rs/parking_lot/
+    #[inline]
+    pub fn try_lock_ignore_poison(
+        &self,
+    ) -> PoisonlessTryLockResult<ThreadCheckedMutexGuard<'_, T>> {
+        self.try_lock().ignore_poison()
+    }
+
+    /// Acquires this mutex, blocking the current thread while the mutex is locked in other
+    /// threads, and runs `f` on the protected data before unlocking the mutex.
+    ///
+    /// Unlike [`lock`], no guard is handed out, so the lock cannot be leaked or held for longer
+    /// than the call to `f`. An attempt to lock this mutex again inside `f` (for instance, with a
+    /// recursive call to `scope`) returns a [`LockedByCurrentThread`] error.
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned, and `f` is not run.
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then `f` is still run,
+    /// but its output is wrapped in a poison error. See the [`HandlePoisonResult`] trait for
+    /// methods to ignore poison errors and treat them as successful, or to panic if a poison
+    /// error was returned.
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`HandlePoisonResult`]: crate::HandlePoisonResult
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    pub fn scope<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> LockResult<R> {
+        match self.lock() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(LockError::Poisoned(poison)) => {
+                let output = f(&mut poison.into_inner());
+                Err(LockError::Poisoned(PoisonError::new(output)))
+            }
+            Err(LockError::LockedByCurrentThread) => Err(LockError::LockedByCurrentThread),
+            Err(LockError::WouldBlock)            => Err(LockError::WouldBlock),
+        }
+    }
+
+    /// Attempts to acquire this mutex without blocking, and if successful, runs `f` on the
+    /// protected data before unlocking the mutex.
+    ///
+    /// Unlike [`try_lock`], no guard is handed out, so the lock cannot be leaked or held for
+    /// longer than the call to `f`. An attempt to lock this mutex again inside `f` (for instance,
+    /// with a recursive call to `try_scope`) returns a [`LockedByCurrentThread`] error.
+    ///
+    /// # Errors
+    /// If the mutex was already held by the current thread when this call was made, then a
+    /// [`LockedByCurrentThread`] error is returned. If the mutex was held by a different thread,
+    /// then a [`WouldBlock`] error is returned. In either case, `f` is not run.
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then `f` is still run,
+    /// but its output is wrapped in a poison error. See the [`HandlePoisonResult`] trait for
+    /// methods to ignore poison errors and treat them as successful, or to panic if a poison
+    /// error was returned.
+    ///
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`HandlePoisonResult`]: crate::HandlePoisonResult
+    /// [`LockedByCurrentThread`]: TryLockError::LockedByCurrentThread
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    pub fn try_scope<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> TryLockResult<R> {
+        match self.try_lock() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(TryLockError::Poisoned(poison)) => {
+                let output = f(&mut poison.into_inner());
+                Err(TryLockError::Poisoned(PoisonError::new(output)))
             }
-            Err(StdTryLockError::WouldBlock) => Err(TryLockError::WouldBlock),
+            Err(TryLockError::LockedByCurrentThread) => Err(TryLockError::LockedByCurrentThread),
+            Err(TryLockError::WouldBlock)            => Err(TryLockError::WouldBlock),
         }
     }
 
+    /// Returns the numeric ID of this mutex, intended for diagnostics such as logging or tracing.
+    ///
+    /// The ID is nonzero and distinct from the ID of every other live `ThreadCheckedMutex`, and
+    /// does not change for the lifetime of this mutex. It is equal to the
+    /// [`mutex_id`] of any guard of this mutex.
+    ///
+    /// If the `recycle-ids` feature is enabled, the ID of a dropped mutex may be reused by a
+    /// mutex created later; otherwise, IDs are never reused within a process.
+    ///
+    /// [`mutex_id`]: ThreadCheckedMutexGuard::mutex_id
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> u64 {
+        self.mutex_id.id().get()
+    }
+
     /// Determines whether this mutex is currently held by the current thread.
     #[inline]
     #[must_use]
     pub fn locked_by_current_thread(&self) -> bool {
-        locked_mutexes::locked_by_current_thread(self.mutex_id)
+        locked_mutexes::locked_by_current_thread(self.mutex_id.id())
+    }
+
+    /// Determines whether this mutex is currently held by any thread, including the current
+    /// thread.
+    ///
+    /// This briefly attempts to acquire the mutex with [`std::sync::Mutex::try_lock`], and
+    /// immediately releases it if the attempt succeeds; a poisoned mutex is reported as unlocked
+    /// unless it is actually held.
+    ///
+    /// Since other threads may lock or unlock the mutex at any time, the returned value may
+    /// already be outdated when it is returned. This function is intended only for diagnostics,
+    /// such as metrics or debugging output, and must not be used to decide whether it is safe to
+    /// lock the mutex.
+    #[inline]
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        matches!(self.mutex.try_lock(), Err(StdTryLockError::WouldBlock))
     }
 
     /// Determines whether this mutex is currently poisoned.
@@ -157,31 +605,90 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
     /// at any time; as such, the return value of this function should generally not be depended on
     /// for program correctness.
     ///
-    /// [Read more about poison.](crate::HandlePoisonResult#about-poison)
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
     #[inline]
     #[must_use]
     pub fn is_poisoned(&self) -> bool {
         self.mutex.is_poisoned()
     }
 
+    /// Determines whether this mutex was created with [`new_ignore_poison`], in which case
+    /// [`lock`] and [`try_lock`] clear and ignore poison.
+    ///
+    /// [`new_ignore_poison`]: ThreadCheckedMutex::new_ignore_poison
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    #[inline]
+    #[must_use]
+    pub const fn ignores_poison(&self) -> bool {
+        self.ignore_poison
+    }
+
+    /// Determines whether this mutex was created with [`new_fair`], in which case [`lock`]
+    /// acquires it in approximately the order in which threads attempt to acquire it.
+    ///
+    /// [`new_fair`]: ThreadCheckedMutex::new_fair
+    /// [`lock`]: ThreadCheckedMutex::lock
+    #[inline]
+    #[must_use]
+    pub const fn is_fair(&self) -> bool {
+        self.queue.is_some()
+    }
+
+    /// Returns a snapshot of how often this mutex has been acquired, found to be contended, or
+    /// rejected an attempt to reenter it. See [`LockStats`] for which attempts are counted.
+    ///
+    /// The counters are specific to this mutex, and start at zero when it is created (or
+    /// deserialized). Only available with the `metrics` feature; without it, no counters are
+    /// stored or updated.
+    #[cfg(feature = "metrics")]
+    #[inline]
+    #[must_use]
+    pub fn stats(&self) -> LockStats {
+        self.counters.snapshot()
+    }
+
     /// Clear any poison from this mutex.
     ///
     /// When a [`ThreadCheckedMutexGuard`] is dropped in a thread which is panicking, its associated
     /// mutex becomes poisoned, and remains poisoned until this function is called (by any thread).
     ///
-    /// [Read more about poison.](crate::HandlePoisonResult#about-poison)
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
     #[inline]
     pub fn clear_poison(&self) {
         self.mutex.clear_poison();
     }
 
+    /// Clear any poison from this mutex, and return whether the mutex was poisoned.
+    ///
+    /// If another thread is active, the mutex could become poisoned again at any time; to clear
+    /// poison while holding the lock, use [`lock_or_clear_poison`].
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`lock_or_clear_poison`]: ThreadCheckedMutex::lock_or_clear_poison
+    #[inline]
+    pub fn take_poison(&self) -> bool {
+        let poisoned = self.is_poisoned();
+        if poisoned {
+            self.clear_poison();
+        }
+        poisoned
+    }
+
     /// Consumes this mutex and returns the underlying data.
     ///
+    /// This does not lock the mutex. Since the mutex is taken by value, no
+    /// [`ThreadCheckedMutexGuard`] for it can exist, so the reentrancy errors of [`lock`] are
+    /// structurally impossible here; poison is the only possible error.
+    ///
     /// # Errors
     /// If another user of this mutex panicked while holding the mutex, then the inner data is
     /// still returned, but wrapped in a poison error.
     ///
-    /// [Read more about poison.](crate::HandlePoisonResult#about-poison)
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
     #[inline]
     pub fn into_inner(self) -> AccessResult<T>
     where
@@ -192,15 +699,41 @@ impl<T: ?Sized> ThreadCheckedMutex<T> {
 
     /// Returns a mutable reference to the underlying data, without locking.
     ///
+    /// Since the mutex is mutably borrowed, no [`ThreadCheckedMutexGuard`] for it can exist, so
+    /// the reentrancy errors of [`lock`] are structurally impossible here; poison is the only
+    /// possible error.
+    ///
+    /// Note that if a guard was leaked (for instance, with [`mem::forget`]), the current thread
+    /// may still be recorded as holding this mutex, and [`locked_by_current_thread`] may return
+    /// `true`. This function still succeeds in that case.
+    ///
     /// # Errors
     /// If another user of this mutex panicked while holding the mutex, then a mutable reference is
     /// still returned, but wrapped in a poison error.
     ///
-    /// [Read more about poison.](crate::HandlePoisonResult#about-poison)
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`locked_by_current_thread`]: ThreadCheckedMutex::locked_by_current_thread
+    /// [`mem::forget`]: std::mem::forget
     #[inline]
     pub fn get_mut(&mut self) -> AccessResult<&mut T> {
         self.mutex.get_mut().map_err(Into::into)
     }
+
+    /// Returns a mutable reference to the underlying data, without locking, and ignoring any
+    /// poison.
+    ///
+    /// Equivalent to `self.get_mut().ignore_poison()`, but without needing to handle a result.
+    /// Any poison is left in place; see [`clear_poison`].
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`clear_poison`]: ThreadCheckedMutex::clear_poison
+    #[inline]
+    pub fn get_mut_ignore_poison(&mut self) -> &mut T {
+        self.mutex.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
 }
 
 impl<T: Default> Default for ThreadCheckedMutex<T> {
@@ -224,53 +757,230 @@ impl<T: Default> Default for ThreadCheckedMutex<T> {
 #[clippy::has_significant_drop]
 #[derive(Debug)]
 pub struct ThreadCheckedMutexGuard<'a, T: ?Sized> {
-    mutex_id: MutexID,
-    guard:    MutexGuard<'a, T>,
+    mutex_id: &'a OwnedMutexID,
+    mutex:    &'a Mutex<T>,
+    /// The queue of the mutex, if it is [fair].
+    ///
+    /// [fair]: ThreadCheckedMutex::new_fair
+    queue:    Option<&'a FairQueue>,
+    /// Only `None` while the lock is temporarily released by [`unlocked`].
+    ///
+    /// [`unlocked`]: ThreadCheckedMutexGuard::unlocked
+    guard:    Option<MutexGuard<'a, T>>,
 }
 
-impl<T: ?Sized> Drop for ThreadCheckedMutexGuard<'_, T> {
+impl<T: ?Sized> ThreadCheckedMutexGuard<'_, T> {
+    /// Returns the numeric ID of the mutex which this guard locks, intended for diagnostics such
+    /// as logging or tracing.
+    ///
+    /// See [`ThreadCheckedMutex::id`].
     #[inline]
-    fn drop(&mut self) {
-        let was_locked = locked_mutexes::register_unlocked(self.mutex_id);
+    #[must_use]
+    pub const fn mutex_id(&self) -> u64 {
+        self.mutex_id.id().get()
+    }
 
-        // This assertion should not fail unless someone used unsound unsafe code.
+    /// Unlocks the mutex by consuming this guard.
+    ///
+    /// Equivalent to `drop(guard)`, but makes the point at which the mutex is unlocked explicit.
+    /// Afterwards, the current thread is no longer recorded as holding the mutex.
+    #[inline]
+    pub fn unlock(self) {
+        drop(self);
+    }
+
+    /// Temporarily releases the mutex, runs `f`, and then reacquires the mutex (blocking the
+    /// current thread while the mutex is locked in other threads).
+    ///
+    /// While `f` runs, the mutex is not held by the current thread, so `f` may lock the mutex
+    /// again without a [`LockedByCurrentThread`] error, and other threads may acquire it. The
+    /// protected data might be changed by the time this function returns.
+    ///
+    /// If `f` panics, the mutex is still reacquired before this guard is dropped, keeping the
+    /// current thread's record of held mutexes consistent. Because the mutex is reacquired while
+    /// the thread is already panicking, dropping this guard does not poison the mutex (matching
+    /// the behavior of [`Mutex`]).
+    ///
+    /// # Poison
+    /// If another user of this mutex panicked while holding the mutex during the call to `f`, the
+    /// mutex is still reacquired, but no poison error is returned. Use
+    /// [`ThreadCheckedMutex::is_poisoned`] to check for poison.
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    pub fn unlocked<R, F: FnOnce() -> R>(&mut self, f: F) -> R {
+        /// Reacquires the mutex when dropped, even if `f` panics.
+        struct Relock<'g, 'a, T: ?Sized>(&'g mut ThreadCheckedMutexGuard<'a, T>);
+
+        impl<T: ?Sized> Drop for Relock<'_, '_, T> {
+            fn drop(&mut self) {
+                let guard = &mut *self.0;
+
+                let was_unlocked = locked_mutexes::register_locked(guard.mutex_id.id());
+                debug_assert!(
+                    was_unlocked,
+                    "a ThreadCheckedMutexGuard was relocked in a thread which already held it",
+                );
+
+                if let Some(queue) = guard.queue {
+                    queue.enter();
+                }
+
+                // Poison is intentionally ignored, as documented above.
+                let relocked = guard.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+                guard.guard = Some(relocked);
+            }
+        }
+
+        // Unlock the mutex, and only then unregister it.
+        self.guard = None;
+        if let Some(queue) = self.queue {
+            queue.release();
+        }
+        let was_locked = locked_mutexes::register_unlocked(self.mutex_id.id());
         debug_assert!(
             was_locked,
-            "a ThreadCheckedMutexGuard was dropped in a thread which it was not locked in",
+            "a ThreadCheckedMutexGuard was unlocked in a thread which it was not locked in",
         );
+
+        let _relock = Relock(self);
+        f()
     }
-}
 
-impl<T: ?Sized> Deref for ThreadCheckedMutexGuard<'_, T> {
-    type Target = T;
+    /// Temporarily releases the mutex to give other threads a chance to acquire it, and then
+    /// reacquires the mutex (blocking the current thread while the mutex is locked in other
+    /// threads).
+    ///
+    /// Equivalent to `guard.unlocked(std::thread::yield_now)`; see [`unlocked`]. This is useful
+    /// in a long critical section, to avoid starving other threads of the mutex.
+    ///
+    /// Other threads may change the protected data before the mutex is reacquired, so any
+    /// invariants that the current thread was relying on should be checked again afterwards.
+    ///
+    /// # Poison
+    /// If another user of this mutex panicked while holding the mutex, the mutex is still
+    /// reacquired, but no poison error is returned. Use [`ThreadCheckedMutex::is_poisoned`] to
+    /// check for poison.
+    ///
+    /// [Read more about poison](crate::HandlePoisonResult#about-poison).
+    ///
+    /// [`unlocked`]: ThreadCheckedMutexGuard::unlocked
+    #[inline]
+    pub fn yield_now(&mut self) {
+        self.unlocked(yield_now);
+    }
 
+    /// Returns the protected data, accessed through the inner [`MutexGuard`] (which is only
+    /// absent while [`unlocked`] runs).
+    ///
+    /// [`unlocked`]: ThreadCheckedMutexGuard::unlocked
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.guard
+    fn inner(&self) -> &T {
+        #[expect(
+            clippy::expect_used,
+            reason = "the guard is only taken during `unlocked`, which borrows the guard mutably",
+        )]
+        self.guard.as_deref().expect("ThreadCheckedMutexGuard should hold the lock")
     }
-}
 
-impl<T: ?Sized> DerefMut for ThreadCheckedMutexGuard<'_, T> {
+    /// Mutably returns the protected data, accessed through the inner [`MutexGuard`] (which is
+    /// only absent while [`unlocked`] runs).
+    ///
+    /// [`unlocked`]: ThreadCheckedMutexGuard::unlocked
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.guard
+    fn inner_mut(&mut self) -> &mut T {
+        #[expect(
+            clippy::expect_used,
+            reason = "the guard is only taken during `unlocked`, which borrows the guard mutably",
+        )]
+        self.guard.as_deref_mut().expect("ThreadCheckedMutexGuard should hold the lock")
     }
 }
 
-impl<T: ?Sized + Display> Display for ThreadCheckedMutexGuard<'_, T> {
+impl<'a, T: ?Sized> ThreadCheckedMutexGuard<'a, T> {
+    /// Converts this guard into the standard library's [`MutexGuard`], for interoperability with
+    /// code which expects one. The mutex remains locked until the returned guard is dropped.
+    ///
+    /// # Forfeited Reentrancy Detection
+    /// Because the eventual unlock cannot be observed, the current thread stops being recorded as
+    /// holding this mutex as soon as this function is called, exactly as though the mutex had been
+    /// acquired with [`ThreadCheckedMutex::lock_unchecked`]. While the returned guard is held,
+    /// [`locked_by_current_thread`] returns `false`, and a call to [`lock`] on the current thread
+    /// may deadlock or panic instead of returning a [`LockedByCurrentThread`] error. [`try_lock`]
+    /// returns a [`WouldBlock`] error.
+    ///
+    /// [`lock`]: ThreadCheckedMutex::lock
+    /// [`try_lock`]: ThreadCheckedMutex::try_lock
+    /// [`locked_by_current_thread`]: ThreadCheckedMutex::locked_by_current_thread
+    /// [`LockedByCurrentThread`]: LockError::LockedByCurrentThread
+    /// [`WouldBlock`]: TryLockError::WouldBlock
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        Display::fmt(&*self.guard, f)
+    #[expect(clippy::missing_panics_doc, reason = "the guard cannot be absent here")]
+    pub fn into_std_guard(mut self) -> MutexGuard<'a, T> {
+        #[expect(
+            clippy::expect_used,
+            reason = "the guard is only taken during `unlocked`, which borrows the guard mutably",
+        )]
+        let guard = self.guard.take().expect("ThreadCheckedMutexGuard should hold the lock");
+
+        // Dropping `self` unregisters the mutex, without unlocking it (as `self.guard` is `None`).
+        drop(self);
+        guard
     }
 }
 
+impl<T: ?Sized> Drop for ThreadCheckedMutexGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(queue) = self.queue {
+            // Unlock the mutex before handing it to the next queued thread.
+            self.guard = None;
+            queue.release();
+        }
 
-#[cfg(test)]
-mod tests {
-    #![expect(clippy::unwrap_used, reason = "these are tests")]
+        let was_locked = locked_mutexes::register_unlocked(self.mutex_id.id());
+        #[cfg(feature = "recycle-ids")]
+        self.mutex_id.guard_dropped();
+
+        // This assertion should not fail unless someone used unsound unsafe code.
+        debug_assert!(
+            was_locked,
+            "a ThreadCheckedMutexGuard was dropped in a thread which it was not locked in",
+        );
+    }
+}
+
+impl<T: ?Sized> Deref for ThreadCheckedMutexGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner()
+    }
+}
+
+impl<T: ?Sized> DerefMut for ThreadCheckedMutexGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner_mut()
+    }
+}
+
+impl<T: ?Sized + Display> Display for ThreadCheckedMutexGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.inner(), f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "these are tests")]
 
     use std::{sync::mpsc, thread};
-    use std::{sync::Arc, time::Duration};
+    use std::{mem, sync::Arc, time::Duration};
 
     use crate::mutex_id::run_this_before_each_test_that_creates_a_mutex_id;
     use super::*;
@@ -304,6 +1014,53 @@ mod tests {
         assert!(!mutex.locked_by_current_thread());
     }
 
+    #[test]
+    fn lock_unchecked_is_unregistered() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        let mut guard: MutexGuard<'_, u8> = mutex.lock_unchecked().unwrap();
+        *guard += 1;
+
+        assert!(!mutex.locked_by_current_thread());
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock)));
+
+        drop(guard);
+
+        assert_eq!(*mutex.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn guard_mutex_id() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        let other = ThreadCheckedMutex::new(0_u8);
+
+        assert_ne!(mutex.id(), 0);
+        assert_ne!(mutex.id(), other.id());
+        assert_eq!(mutex.lock().unwrap().mutex_id(), mutex.id());
+    }
+
+    #[test]
+    fn into_std_guard_is_unregistered() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        let mut guard: MutexGuard<'_, u8> = mutex.lock().unwrap().into_std_guard();
+        *guard += 1;
+
+        assert!(!mutex.locked_by_current_thread());
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock)));
+
+        drop(guard);
+
+        assert_eq!(*mutex.lock().unwrap(), 1);
+        assert!(!mutex.locked_by_current_thread());
+    }
+
     #[test]
     fn lock_unlock_lock() {
         run_this_before_each_test_that_creates_a_mutex_id();
@@ -321,6 +1078,21 @@ mod tests {
         assert!(mutex.locked_by_current_thread());
     }
 
+    #[test]
+    fn explicit_unlock() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        let mut guard = mutex.lock().unwrap();
+        *guard += 1;
+        guard.unlock();
+
+        assert!(!mutex.locked_by_current_thread());
+        assert!(!mutex.is_locked());
+        assert_eq!(*mutex.lock().unwrap(), 1);
+    }
+
     #[test]
     fn lock_lock_unlock_lock() {
         run_this_before_each_test_that_creates_a_mutex_id();
@@ -369,6 +1141,39 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn is_locked() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = Arc::new(ThreadCheckedMutex::new(()));
+        let (locking_sender, locking_receiver) = mpsc::channel();
+        let (unlocking_sender, unlocking_receiver) = mpsc::channel();
+
+        assert!(!mutex.is_locked());
+
+        let own_guard = mutex.lock().unwrap();
+        assert!(mutex.is_locked());
+        drop(own_guard);
+
+        let mutex_clone = Arc::clone(&mutex);
+
+        let handle = thread::spawn(move || {
+            let guard = mutex_clone.lock().unwrap();
+            locking_sender.send(()).unwrap();
+            unlocking_receiver.recv().unwrap();
+            drop(guard);
+        });
+
+        locking_receiver.recv().unwrap();
+        assert!(mutex.is_locked());
+        // Checking must not have acquired the mutex.
+        assert!(mutex.is_locked());
+
+        unlocking_sender.send(()).unwrap();
+        handle.join().unwrap();
+        assert!(!mutex.is_locked());
+    }
+
     #[test]
     fn would_block() {
         run_this_before_each_test_that_creates_a_mutex_id();
@@ -409,4 +1214,443 @@ mod tests {
         // Now `lock` should work, though `try_lock` might not.
         let _guard = mutex.lock().unwrap();
     }
+
+    #[test]
+    fn scope_unlocks() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        let output = mutex.scope(|data| {
+            *data += 1;
+            *data
+        }).unwrap();
+
+        assert_eq!(output, 1);
+        assert!(!mutex.locked_by_current_thread());
+
+        assert_eq!(mutex.try_scope(|data| *data).unwrap(), 1);
+        assert!(!mutex.locked_by_current_thread());
+    }
+
+    #[test]
+    fn recursive_scope() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        mutex.scope(|_| {
+            assert!(matches!(
+                mutex.scope(|_| ()),
+                Err(LockError::LockedByCurrentThread),
+            ));
+            assert!(matches!(
+                mutex.try_scope(|_| ()),
+                Err(TryLockError::LockedByCurrentThread),
+            ));
+        }).unwrap();
+    }
+
+    #[test]
+    fn poisoned_scope() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = Arc::new(ThreadCheckedMutex::new(0_u8));
+        let mutex_clone = Arc::clone(&mutex);
+
+        thread::spawn(move || {
+            let _guard = mutex_clone.lock().unwrap();
+            #[expect(clippy::panic, reason = "poison the mutex")]
+            {
+                panic!("poisoning the mutex");
+            }
+        }).join().unwrap_err();
+
+        let result = mutex.scope(|data| {
+            *data += 1;
+            *data
+        });
+        assert!(matches!(result, Err(LockError::Poisoned(_))));
+        assert_eq!(result.ignore_poison().unwrap(), 1);
+
+        assert!(matches!(
+            mutex.try_scope(|data| *data),
+            Err(TryLockError::Poisoned(_)),
+        ));
+        assert!(!mutex.locked_by_current_thread());
+    }
+
+    #[test]
+    fn unlocked_then_relocked() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        let mut guard = mutex.lock().unwrap();
+
+        guard.unlocked(|| {
+            assert!(!mutex.locked_by_current_thread());
+            *mutex.lock().unwrap() += 1;
+        });
+
+        assert!(mutex.locked_by_current_thread());
+        assert_eq!(*guard, 1);
+        assert!(matches!(
+            mutex.try_lock(),
+            Err(TryLockError::LockedByCurrentThread),
+        ));
+
+        drop(guard);
+        assert!(!mutex.locked_by_current_thread());
+    }
+
+    #[test]
+    fn yield_now_lets_other_threads_lock() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        let mut guard = mutex.lock().unwrap();
+
+        thread::scope(|scope| {
+            let other = scope.spawn(|| *mutex.lock().unwrap() += 1);
+
+            while !other.is_finished() {
+                guard.yield_now();
+            }
+        });
+
+        assert!(mutex.locked_by_current_thread());
+        assert_eq!(*guard, 1);
+        drop(guard);
+        assert!(!mutex.locked_by_current_thread());
+    }
+
+    #[test]
+    fn panic_while_unlocked() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                let mut guard = mutex.lock().unwrap();
+                guard.unlocked(|| {
+                    #[expect(clippy::panic, reason = "panic while the mutex is unlocked")]
+                    {
+                        panic!("panicking while unlocked");
+                    }
+                });
+            }).join().unwrap_err();
+        });
+
+        // The guard was relocked during unwinding, so dropping it did not poison the mutex.
+        assert!(!mutex.is_poisoned());
+        assert!(!mutex.locked_by_current_thread());
+        drop(mutex.lock().unwrap());
+    }
+
+    #[test]
+    fn lock_ignore_poison() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                let _guard = mutex.lock().unwrap();
+                #[expect(clippy::panic, reason = "poison the mutex")]
+                {
+                    panic!("poisoning the mutex");
+                }
+            }).join().unwrap_err();
+        });
+
+        let guard = mutex.lock_ignore_poison().unwrap();
+        assert!(matches!(
+            mutex.lock_ignore_poison(),
+            Err(LockError::LockedByCurrentThread),
+        ));
+        assert!(matches!(
+            mutex.try_lock_ignore_poison(),
+            Err(TryLockError::LockedByCurrentThread),
+        ));
+        drop(guard);
+
+        let _guard = mutex.try_lock_ignore_poison().unwrap();
+        assert!(mutex.is_poisoned());
+    }
+
+    #[test]
+    fn clear_poison_helpers() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        let poison = || thread::scope(|scope| {
+            scope.spawn(|| {
+                let _guard = mutex.lock().unwrap();
+                #[expect(clippy::panic, reason = "poison the mutex")]
+                {
+                    panic!("poisoning the mutex");
+                }
+            }).join().unwrap_err();
+        });
+
+        poison();
+        let guard = mutex.lock_or_clear_poison().unwrap();
+        assert!(!mutex.is_poisoned());
+        assert!(matches!(
+            mutex.lock_or_clear_poison(),
+            Err(LockError::LockedByCurrentThread),
+        ));
+        drop(guard);
+
+        assert!(!mutex.take_poison());
+        poison();
+        assert!(mutex.take_poison());
+        assert!(!mutex.is_poisoned());
+    }
+
+    #[test]
+    fn lock_recover() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(vec![1_u8, 2]);
+
+        let (first_guard, first_poisoned) = mutex.lock_recover().unwrap();
+        assert!(!first_poisoned);
+        drop(first_guard);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                let mut writer = mutex.lock().unwrap();
+                writer.push(3);
+                #[expect(clippy::panic, reason = "poison the mutex")]
+                {
+                    panic!("poisoning the mutex");
+                }
+            }).join().unwrap_err();
+        });
+        assert!(mutex.is_poisoned());
+
+        let (mut recovered, was_poisoned) = mutex.lock_recover().unwrap();
+        assert!(was_poisoned);
+        assert!(!mutex.is_poisoned());
+        assert!(matches!(
+            mutex.lock_recover(),
+            Err(LockError::LockedByCurrentThread),
+        ));
+        recovered.truncate(2);
+        drop(recovered);
+
+        let (repaired, poisoned_again) = mutex.lock_recover().unwrap();
+        assert!(!poisoned_again);
+        assert_eq!(*repaired, [1, 2]);
+    }
+
+    #[test]
+    fn new_ignore_poison() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new_ignore_poison(0_u8);
+        let poison = || thread::scope(|scope| {
+            scope.spawn(|| {
+                let _guard = mutex.lock().unwrap();
+                #[expect(clippy::panic, reason = "poison the mutex")]
+                {
+                    panic!("poisoning the mutex");
+                }
+            }).join().unwrap_err();
+        });
+
+        assert!(mutex.ignores_poison());
+        assert!(!ThreadCheckedMutex::new(0_u8).ignores_poison());
+
+        poison();
+        assert!(mutex.is_poisoned());
+        let guard = mutex.lock().unwrap();
+        assert!(!mutex.is_poisoned());
+        assert!(matches!(mutex.lock(), Err(LockError::LockedByCurrentThread)));
+        drop(guard);
+
+        poison();
+        drop(mutex.try_lock().unwrap());
+        assert!(!mutex.is_poisoned());
+    }
+
+    #[test]
+    fn get_mut_ignore_poison() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mut mutex = ThreadCheckedMutex::new(0_u8);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                let _guard = mutex.lock().unwrap();
+                #[expect(clippy::panic, reason = "poison the mutex")]
+                {
+                    panic!("poisoning the mutex");
+                }
+            }).join().unwrap_err();
+        });
+
+        *mutex.get_mut_ignore_poison() += 1;
+
+        assert!(mutex.is_poisoned());
+        assert_eq!(mutex.into_inner().ignore_poison().unwrap(), 1);
+    }
+
+    #[test]
+    fn get_mut_after_leaked_guard() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mut mutex = ThreadCheckedMutex::new(0_u8);
+        #[expect(clippy::mem_forget, reason = "leaking a guard is the point of the test")]
+        mem::forget(mutex.lock().unwrap());
+
+        // The leaked guard is still recorded, but `get_mut` does not lock the mutex.
+        assert!(mutex.locked_by_current_thread());
+        *mutex.get_mut().unwrap() += 1;
+        assert_eq!(mutex.into_inner().unwrap(), 1);
+    }
+
+    #[test]
+    fn fair_lock_order() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = Arc::new(ThreadCheckedMutex::new_fair(Vec::new()));
+        assert!(mutex.is_fair());
+        assert!(!ThreadCheckedMutex::new(0_u8).is_fair());
+
+        let guard = mutex.lock().unwrap();
+
+        let handles: Vec<_> = (0..4_u8).map(|index| {
+            let mutex_clone = Arc::clone(&mutex);
+            let handle = thread::spawn(move || mutex_clone.lock().unwrap().push(index));
+
+            // Wait for the thread to join the queue before spawning the next one.
+            while mutex.queue.as_ref().unwrap().len() < usize::from(index) + 2 {
+                yield_now();
+            }
+            handle
+        }).collect();
+
+        drop(guard);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(*mutex.lock().unwrap(), [0, 1, 2, 3]);
+        assert_eq!(mutex.queue.as_ref().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn fair_reentrancy_and_try_lock() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new_fair(0_u8);
+        let queue_len = || mutex.queue.as_ref().unwrap().len();
+
+        let mut guard = mutex.lock().unwrap();
+        assert!(matches!(mutex.lock(), Err(LockError::LockedByCurrentThread)));
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::LockedByCurrentThread)));
+        thread::scope(|scope| {
+            scope.spawn(|| assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock))));
+        });
+        assert_eq!(queue_len(), 1);
+
+        guard.unlocked(|| *mutex.lock().unwrap() += 1);
+        drop(guard);
+        assert_eq!(queue_len(), 0);
+
+        let unchecked = mutex.lock_unchecked().unwrap();
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock)));
+        drop(unchecked);
+        assert_eq!(queue_len(), 0);
+
+        assert_eq!(*mutex.try_lock().unwrap(), 1);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn stats_counters() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        assert_eq!(mutex.stats(), LockStats::default());
+
+        let guard = mutex.lock().unwrap();
+        assert!(matches!(mutex.lock(), Err(LockError::LockedByCurrentThread)));
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::LockedByCurrentThread)));
+        thread::scope(|scope| {
+            scope.spawn(|| assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock))));
+        });
+        drop(guard);
+
+        drop(mutex.try_lock().unwrap());
+        drop(mutex.lock_unchecked().unwrap());
+
+        assert_eq!(mutex.stats(), LockStats {
+            acquisitions:         2,
+            contended:            1,
+            reentrant_rejections: 2,
+        });
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn stats_do_not_disturb_try_lock() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+
+        // An uncontended `lock` acquires the mutex once, without probing it first, so a
+        // `try_lock` in another thread right after the guard is dropped succeeds.
+        for _ in 0..100_u8 {
+            drop(mutex.lock().unwrap());
+            thread::scope(|scope| {
+                scope.spawn(|| drop(mutex.try_lock().unwrap()));
+            });
+        }
+        assert_eq!(mutex.stats().contended, 0);
+
+        let guard = mutex.lock().unwrap();
+        thread::scope(|scope| {
+            let waiter = scope.spawn(|| *mutex.lock().unwrap() += 1);
+
+            // The waiter is counted as contended just before it blocks, and only once.
+            while mutex.stats().contended == 0 {
+                yield_now();
+            }
+            assert!(!waiter.is_finished());
+            drop(guard);
+            waiter.join().unwrap();
+        });
+
+        assert_eq!(mutex.stats(), LockStats {
+            acquisitions:         202,
+            contended:            1,
+            reentrant_rejections: 0,
+        });
+        assert_eq!(*mutex.try_lock().unwrap(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_assigns_fresh_id() {
+        use serde::de::{value::{Error as ValueError, U32Deserializer}, IntoDeserializer as _};
+
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let original = ThreadCheckedMutex::new(5_u32);
+        let _guard = original.lock().unwrap();
+
+        // The mutex is serialized as its inner `Mutex<u32>`, which is just a `u32`.
+        let deserializer: U32Deserializer<ValueError> = 5_u32.into_deserializer();
+        let first = ThreadCheckedMutex::<u32>::deserialize(deserializer).unwrap();
+        let second = ThreadCheckedMutex::<u32>::deserialize(deserializer).unwrap();
+
+        assert_ne!(first.id(), original.id());
+        assert_ne!(first.id(), second.id());
+        assert!(!first.locked_by_current_thread());
+        assert_eq!(*first.lock().unwrap(), 5);
+    }
 }
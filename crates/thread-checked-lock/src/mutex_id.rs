@@ -5,29 +5,143 @@
 
 use std::num::NonZeroU64;
 #[cfg(target_has_atomic = "64")]
-use std::sync::atomic::{AtomicU64, Ordering};
-#[cfg(not(target_has_atomic = "64"))]
+use std::sync::atomic::AtomicU64;
+#[cfg(feature = "recycle-ids")]
+use std::sync::atomic::AtomicBool;
+#[cfg(any(feature = "recycle-ids", target_has_atomic = "64"))]
+use std::sync::atomic::Ordering;
+#[cfg(any(feature = "recycle-ids", not(target_has_atomic = "64")))]
 use std::sync::Mutex;
+#[cfg(feature = "recycle-ids")]
+use std::sync::PoisonError;
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "recycle-ids")]
+use crate::locked_mutexes;
 
 
 /// A unique `MutexId` should be assigned to each `ThreadCheckedMutex` so that each thread
 /// can track which mutexes they have acquired.
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct MutexID(NonZeroU64);
 
+impl MutexID {
+    /// Returns the numeric value of this ID, which is never zero.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn get(self) -> u64 {
+        self.0.get()
+    }
+}
+
+/// Owns the [`MutexID`] of a `ThreadCheckedMutex`.
+///
+/// If the `recycle-ids` feature is enabled, the ID is returned to a free list when this is
+/// dropped, and may be returned by a later call to [`next_id`]. The ID is not recycled if a guard
+/// of the mutex was leaked, in any thread, since that thread may still have the ID registered as
+/// locked. It is also not recycled if this is dropped while the current thread's registry of
+/// locked mutexes is unavailable (for instance, in the destructor of another thread-local); in
+/// that case, the ID is leaked.
+#[derive(Debug)]
+pub(crate) struct OwnedMutexID {
+    id:      MutexID,
+    /// Whether a `ThreadCheckedMutexGuard` of the mutex exists, in any thread. Only reset when
+    /// the guard is dropped, so it remains set if the guard was leaked.
+    #[cfg(feature = "recycle-ids")]
+    guarded: AtomicBool,
+}
+
+impl OwnedMutexID {
+    /// Returns a new `OwnedMutexID` that does not share its [`MutexID`] with any other live
+    /// `OwnedMutexID` created by this function.
+    #[inline]
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self {
+            id:      next_id(),
+            #[cfg(feature = "recycle-ids")]
+            guarded: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the [`MutexID`] owned by this value.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn id(&self) -> MutexID {
+        self.id
+    }
+
+    /// Records that a `ThreadCheckedMutexGuard` of the mutex was created.
+    #[cfg(feature = "recycle-ids")]
+    #[inline]
+    pub(crate) fn guard_created(&self) {
+        self.guarded.store(true, Ordering::Relaxed);
+    }
+
+    /// Records that the `ThreadCheckedMutexGuard` of the mutex was dropped.
+    #[cfg(feature = "recycle-ids")]
+    #[inline]
+    pub(crate) fn guard_dropped(&self) {
+        self.guarded.store(false, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "recycle-ids")]
+impl Drop for OwnedMutexID {
+    #[inline]
+    fn drop(&mut self) {
+        let guard_leaked = *self.guarded.get_mut();
+        if !guard_leaked && locked_mutexes::try_locked_by_current_thread(self.id) == Some(false) {
+            recycle_id(self.id);
+        }
+    }
+}
+
 /// 2^63, which is basically half of [`u64::MAX`].
 const MAX_MUTEXES_PER_PROCESS: u64 = 1 << 63;
 
 
+/// IDs which were owned by dropped `OwnedMutexID`s, and which may be reused.
+#[cfg(feature = "recycle-ids")]
+static FREE_IDS: Mutex<Vec<MutexID>> = Mutex::new(Vec::new());
+
+/// Returns a `MutexID` to the free list, so that it may be returned by [`next_id`].
+///
+/// The ID must not be in use by any other `ThreadCheckedMutex`.
+#[cfg(feature = "recycle-ids")]
+fn recycle_id(id: MutexID) {
+    // Poison can be ignored, since the vector is left in a valid state even if
+    // `push` or `pop` panic.
+    FREE_IDS.lock().unwrap_or_else(PoisonError::into_inner).push(id);
+}
+
+/// Returns a `MutexID` that is not currently in use.
+///
+/// If the `recycle-ids` feature is enabled, this returns a recycled ID if one is available.
+/// Otherwise, it returns a unique `MutexID` that was not returned on any previous call in the
+/// program to this function.
+pub(crate) fn next_id() -> MutexID {
+    #[cfg(feature = "recycle-ids")]
+    {
+        let recycled = FREE_IDS.lock().unwrap_or_else(PoisonError::into_inner).pop();
+        if let Some(id) = recycled {
+            return id;
+        }
+    }
+
+    new_id()
+}
+
 /// Returns a unique `MutexID` that was not returned on any previous call in the program to this
 /// function.
-pub(crate) fn next_id() -> MutexID {
-    let counter = next_counter();
+fn new_id() -> MutexID {
+    id_from_counter(next_counter())
+}
 
+/// Converts a counter value into the corresponding `MutexID`, which is `counter + 1`.
+///
+/// # Panics
+/// Panics if `counter` is at least [`MAX_MUTEXES_PER_PROCESS`].
+fn id_from_counter(counter: u64) -> MutexID {
     // There are `max` counter values in `0..max`.
     assert!(
         counter < MAX_MUTEXES_PER_PROCESS,
@@ -56,29 +170,41 @@ fn next_counter() -> u64 {
 
 /// Sequentially return the next `u64`, starting at `0` when first called in the program.
 ///
-/// Could theoretically wrap back to `0`.
+/// Saturates at [`u64::MAX`] instead of wrapping, so every call after the cap is reached is
+/// rejected by [`id_from_counter`].
 #[cfg(not(target_has_atomic = "64"))]
 #[inline]
 fn next_counter() -> u64 {
     static ID_COUNTER: Mutex<u64> = Mutex::new(0);
 
-    // On no thread will `wrapping_add` or loads and stores panic.
+    // On no thread will `saturating_add` or loads and stores panic.
     #[expect(
         clippy::unwrap_used,
-        reason = "Mutex can only be poisoned if the following three lines can panic",
+        reason = "Mutex can only be poisoned if `advance_counter` can panic",
     )]
     let mut counter_guard = ID_COUNTER.lock().unwrap();
-    let counter: u64 = *counter_guard;
-    *counter_guard = counter.wrapping_add(1);
-    counter
+    advance_counter(&mut counter_guard)
 }
 
+/// Increments `counter` without wrapping, and returns its previous value.
+#[cfg(any(test, not(target_has_atomic = "64")))]
+#[inline]
+const fn advance_counter(counter: &mut u64) -> u64 {
+    let current = *counter;
+    *counter = current.saturating_add(1);
+    current
+}
 
 #[cfg(test)]
 pub(crate) use self::tests::run_this_before_each_test_that_creates_a_mutex_id;
 
 #[cfg(test)]
 mod tests {
+    #![cfg_attr(
+        feature = "recycle-ids",
+        expect(clippy::unwrap_used, reason = "these are tests"),
+    )]
+
     use std::sync::Once;
     use super::*;
 
@@ -93,7 +219,7 @@ mod tests {
         // to fail.
         #[inline(never)]
         fn test_first_mutex_id() {
-            assert_eq!(next_id(), first_id());
+            assert_eq!(new_id(), first_id());
         }
 
         static ONCE: Once = Once::new();
@@ -107,4 +233,98 @@ mod tests {
 
         assert_ne!(next_id(), first_id());
     }
+
+    #[test]
+    fn id_just_below_cap() {
+        let id = id_from_counter(MAX_MUTEXES_PER_PROCESS - 1);
+        assert_eq!(id.get(), MAX_MUTEXES_PER_PROCESS);
+    }
+
+    #[test]
+    #[should_panic = "Only 2^63 thread-checked mutexes may be created in one process"]
+    fn id_at_cap() {
+        let _id = id_from_counter(MAX_MUTEXES_PER_PROCESS);
+    }
+
+    #[test]
+    #[should_panic = "Only 2^63 thread-checked mutexes may be created in one process"]
+    fn id_at_counter_max() {
+        let _id = id_from_counter(u64::MAX);
+    }
+
+    #[test]
+    fn advance_counter_increments_and_saturates() {
+        let mut counter = 0;
+        assert_eq!(advance_counter(&mut counter), 0);
+        assert_eq!(advance_counter(&mut counter), 1);
+        assert_eq!(counter, 2);
+
+        let mut near_max = u64::MAX - 1;
+        assert_eq!(advance_counter(&mut near_max), u64::MAX - 1);
+        assert_eq!(advance_counter(&mut near_max), u64::MAX);
+        assert_eq!(advance_counter(&mut near_max), u64::MAX);
+        assert_eq!(near_max, u64::MAX);
+    }
+
+    #[cfg(feature = "recycle-ids")]
+    #[test]
+    fn leaked_id_not_recycled() {
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let owned = OwnedMutexID::new();
+        let id = owned.id();
+
+        assert!(locked_mutexes::register_locked(id));
+        drop(owned);
+
+        assert!(!FREE_IDS.lock().unwrap_or_else(PoisonError::into_inner).contains(&id));
+        assert!(locked_mutexes::register_unlocked(id));
+    }
+
+    #[cfg(feature = "recycle-ids")]
+    fn is_free(id: u64) -> bool {
+        FREE_IDS.lock().unwrap_or_else(PoisonError::into_inner).iter().any(|free| free.get() == id)
+    }
+
+    #[cfg(feature = "recycle-ids")]
+    #[test]
+    fn id_leaked_in_other_thread_not_recycled() {
+        use std::{mem, thread};
+        use crate::ThreadCheckedMutex;
+
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        let mutex = ThreadCheckedMutex::new(0_u8);
+        let id = mutex.id();
+
+        thread::scope(|scope| {
+            #[expect(clippy::mem_forget, reason = "leaking a guard is the point of the test")]
+            scope.spawn(|| mem::forget(mutex.lock().unwrap()));
+        });
+        drop(mutex);
+
+        assert!(!is_free(id));
+    }
+
+    #[cfg(feature = "recycle-ids")]
+    #[test]
+    fn drop_in_thread_local_destructor() {
+        use std::{cell::RefCell, thread};
+        use crate::ThreadCheckedMutex;
+
+        thread_local! {
+            static HOLDER: RefCell<Option<ThreadCheckedMutex<u8>>> = const { RefCell::new(None) };
+        }
+
+        run_this_before_each_test_that_creates_a_mutex_id();
+
+        thread::spawn(|| {
+            HOLDER.with(|holder| *holder.borrow_mut() = Some(ThreadCheckedMutex::new(0)));
+
+            // Access the registry of locked mutexes only after `HOLDER`, so that (on most
+            // platforms) the registry is destroyed before the held mutex is dropped.
+            let other = ThreadCheckedMutex::new(0_u8);
+            drop(other.lock().unwrap());
+        }).join().unwrap();
+    }
 }